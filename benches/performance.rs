@@ -60,6 +60,26 @@ fn create_calendar_with_overlaps(num_events: usize) -> Calendar {
     cal
 }
 
+/// Create a chain of `n` 45-minute events starting 30 minutes apart, so each
+/// overlaps only its neighbours.
+fn create_staggered_chain(num_events: usize) -> Calendar {
+    let mut cal = Calendar::new("Chain");
+    let tz = timezone::parse_timezone("UTC").unwrap();
+    let base = timezone::parse_datetime_with_tz("2025-01-01 00:00:00", tz).unwrap();
+
+    for i in 0..num_events {
+        let start = base + Duration::minutes(i as i64 * 30);
+        let event = Event::builder()
+            .title(format!("Link {}", i))
+            .start_datetime(start)
+            .end_datetime(start + Duration::minutes(45))
+            .build()
+            .unwrap();
+        cal.add_event(event);
+    }
+    cal
+}
+
 /// Create a large calendar mixing one-off and recurring events.
 fn create_large_mixed_calendar(one_off: usize, recurring: usize) -> Calendar {
     let mut cal = Calendar::new("Large Mixed Calendar");
@@ -109,6 +129,21 @@ fn bench_find_overlaps(c: &mut Criterion) {
             })
         });
     }
+
+    // Staggered chain — 1999 neighbour overlaps; a pairwise scan is quadratic
+    {
+        let cal = create_staggered_chain(2000);
+        let tz = timezone::parse_timezone("UTC").unwrap();
+        let start = timezone::parse_datetime_with_tz("2025-01-01 00:00:00", tz).unwrap();
+        let end = start + Duration::days(60);
+
+        group.bench_function("staggered_chain_2000", |b| {
+            b.iter(|| {
+                gap_validation::find_overlaps(black_box(&cal), black_box(start), black_box(end))
+                    .unwrap()
+            })
+        });
+    }
     group.finish();
 }
 
@@ -1,13 +1,17 @@
 //! Calendar type for managing collections of events
 
+use crate::clock::{Clock, SystemClock};
 use crate::error::{EventixError, Result};
-use crate::event::Event;
+use crate::event::{Classification, Event, EventOverride, EventStatus, Transparency};
+use crate::gap_validation::{find_gaps, TimeGap};
 use crate::recurrence::Recurrence;
 use crate::timezone::local_day_window;
 use crate::views::{DayIterator, WeekIterator};
-use chrono::DateTime;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Weekday};
 use chrono_tz::Tz;
 use rrule::Frequency;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 
 /// A calendar containing multiple events
 #[derive(Debug, Clone)]
@@ -23,6 +27,12 @@ pub struct Calendar {
 
     /// Calendar timezone (default for new events)
     pub timezone: Option<Tz>,
+
+    /// Web page for the calendar (RFC 7986 `URL`)
+    pub url: Option<String>,
+
+    /// Location subscribers refresh the calendar from (RFC 7986 `SOURCE`)
+    pub source: Option<String>,
 }
 
 impl Calendar {
@@ -41,9 +51,16 @@ impl Calendar {
             description: None,
             events: Vec::new(),
             timezone: None,
+            url: None,
+            source: None,
         }
     }
 
+    /// Create a new calendar builder
+    pub fn builder() -> CalendarBuilder {
+        CalendarBuilder::new()
+    }
+
     /// Set the calendar description
     pub fn description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
@@ -56,6 +73,18 @@ impl Calendar {
         self
     }
 
+    /// Set the web page for this calendar
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Set the URI subscribers refresh this calendar from
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
     /// Add an event to the calendar
     ///
     /// # Examples
@@ -119,11 +148,56 @@ impl Calendar {
         self.events.get_mut(index).map(f)
     }
 
+    /// Keep only the events for which the predicate returns `true`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event, EventStatus};
+    ///
+    /// let mut cal = Calendar::new("Bookings");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Dropped")
+    ///         .start("2025-11-01 10:00:00", "UTC")
+    ///         .duration_hours(1)
+    ///         .status(EventStatus::Cancelled)
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// cal.retain(|e| e.is_active());
+    /// assert_eq!(cal.event_count(), 0);
+    /// ```
+    pub fn retain(&mut self, f: impl Fn(&Event) -> bool) {
+        self.events.retain(f);
+    }
+
     /// Get all events in the calendar
     pub fn get_events(&self) -> &[Event] {
         &self.events
     }
 
+    /// Get the event at `index`, or `None` if it is out of range
+    pub fn event(&self, index: usize) -> Option<&Event> {
+        self.events.get(index)
+    }
+
+    /// Get a mutable reference to the event at `index`, or `None` if it is out of range
+    pub fn event_mut(&mut self, index: usize) -> Option<&mut Event> {
+        self.events.get_mut(index)
+    }
+
+    /// Number of events in the calendar (same as [`event_count`](Self::event_count))
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if the calendar has no events
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
     /// Find events by title (case-insensitive partial match)
     ///
     /// # Examples
@@ -151,6 +225,88 @@ impl Calendar {
             .collect()
     }
 
+    /// Find occurrences of events whose title matches, within a date range
+    ///
+    /// Uses the same case-insensitive partial match as
+    /// [`find_events_by_title`](Self::find_events_by_title), but expands
+    /// recurring events and returns each matching occurrence in
+    /// chronological order. Only matching events are expanded.
+    pub fn find_occurrences_by_title(
+        &self,
+        title: &str,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Result<Vec<EventOccurrence<'_>>> {
+        if start > end {
+            return Err(EventixError::ValidationError(
+                "Start time must be before or equal to end time".to_string(),
+            ));
+        }
+
+        let title_lower = title.to_lowercase();
+        let mut occurrences = Vec::new();
+
+        for (index, event) in self.events.iter().enumerate() {
+            if !event.title.to_lowercase().contains(&title_lower) {
+                continue;
+            }
+            for occurrence_time in event.occurrences_between(start, end, 100_000)? {
+                occurrences.push(EventOccurrence {
+                    event_index: index,
+                    event,
+                    occurrence_time,
+                });
+            }
+        }
+
+        occurrences.sort_by_key(|o| o.occurrence_time);
+        Ok(occurrences)
+    }
+
+    /// Find events tagged with a category (case-insensitive exact match)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event};
+    ///
+    /// let mut cal = Calendar::new("My Calendar");
+    /// let event = Event::builder()
+    ///     .title("Incident Review")
+    ///     .start("2025-11-01 10:00:00", "UTC")
+    ///     .duration_hours(1)
+    ///     .category("Work")
+    ///     .category("Urgent")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// cal.add_event(event);
+    /// assert_eq!(cal.find_events_by_category("urgent").len(), 1);
+    /// ```
+    pub fn find_events_by_category(&self, category: &str) -> Vec<&Event> {
+        self.events
+            .iter()
+            .filter(|e| e.categories.iter().any(|c| c.eq_ignore_ascii_case(category)))
+            .collect()
+    }
+
+    /// Get the earliest start and latest end across all events
+    ///
+    /// Only each event's base occurrence is considered; recurrences are not
+    /// expanded. Times compare by instant, so events in different timezones
+    /// are ordered correctly, and each bound keeps its event's own timezone.
+    /// Returns `None` for an empty calendar.
+    pub fn time_span(&self) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+        let earliest = self.events.iter().map(|e| e.start_time).min()?;
+        let latest = self.events.iter().map(|e| e.end_time).max()?;
+        Some((earliest, latest))
+    }
+
+    /// Find events with the given booking status
+    pub fn filter_by_status(&self, status: EventStatus) -> Vec<&Event> {
+        self.events.iter().filter(|e| e.status == status).collect()
+    }
+
     /// Get all events occurring within a date range
     ///
     /// This expands recurring events into individual occurrences.
@@ -188,11 +344,14 @@ impl Calendar {
             ));
         }
 
-        let mut occurrences = Vec::new();
+        #[cfg(feature = "rayon")]
+        let per_event = self.expand_parallel(start, end, max_per_event)?;
+        #[cfg(not(feature = "rayon"))]
+        let per_event = self.expand_serial(start, end, max_per_event)?;
 
-        for (index, event) in self.events.iter().enumerate() {
-            let event_occurrences = event.occurrences_between(start, end, max_per_event)?;
+        let mut occurrences = Vec::new();
 
+        for (index, (event, event_occurrences)) in self.events.iter().zip(per_event).enumerate() {
             for occurrence_time in event_occurrences {
                 occurrences.push(EventOccurrence {
                     event_index: index,
@@ -202,18 +361,1098 @@ impl Calendar {
             }
         }
 
-        // Sort by occurrence time
+        // Sort by occurrence time (stable, so ties keep event order)
         occurrences.sort_by_key(|o| o.occurrence_time);
 
         Ok(occurrences)
     }
 
+    /// Expand each event's occurrences in turn, in event order
+    #[cfg(any(not(feature = "rayon"), test))]
+    fn expand_serial(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        max_per_event: usize,
+    ) -> Result<Vec<Vec<DateTime<Tz>>>> {
+        self.events.iter().map(|e| expand_event(e, start, end, max_per_event)).collect()
+    }
+
+    /// Expand events' occurrences across the rayon thread pool, in event order
+    #[cfg(feature = "rayon")]
+    fn expand_parallel(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        max_per_event: usize,
+    ) -> Result<Vec<Vec<DateTime<Tz>>>> {
+        use rayon::prelude::*;
+
+        self.events
+            .par_iter()
+            .map(|e| expand_event(e, start, end, max_per_event))
+            .collect()
+    }
+
+    /// Get the earliest `limit` occurrences within a date range
+    ///
+    /// Occurrences from all events are merged by time, and expansion stops
+    /// as soon as `limit` occurrences have been collected, so each series is
+    /// only expanded as far as needed rather than in full. Ties between
+    /// events are broken by event index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event, Recurrence};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let mut cal = Calendar::new("Team");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Standup")
+    ///         .start("2025-11-01 09:00:00", "UTC")
+    ///         .duration_minutes(15)
+    ///         .recurrence(Recurrence::daily())
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let start = parse_datetime_with_tz("2025-11-01 00:00:00", tz).unwrap();
+    /// let end = parse_datetime_with_tz("2035-11-01 00:00:00", tz).unwrap();
+    ///
+    /// let first = cal.first_occurrences_between(start, end, 5).unwrap();
+    /// assert_eq!(first.len(), 5);
+    /// ```
+    pub fn first_occurrences_between(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        limit: usize,
+    ) -> Result<Vec<EventOccurrence<'_>>> {
+        if start > end {
+            return Err(crate::error::EventixError::ValidationError(
+                "Start time must be before or equal to end time".to_string(),
+            ));
+        }
+
+        let mut occurrences = Vec::new();
+        if limit == 0 {
+            return Ok(occurrences);
+        }
+
+        let mut series: Vec<_> = self.events.iter().map(|e| e.occurrences_in(start, end)).collect();
+
+        // Min-heap holding the next pending occurrence of each event
+        let mut heap = BinaryHeap::new();
+        for (index, iter) in series.iter_mut().enumerate() {
+            if let Some(time) = iter.next() {
+                heap.push(Reverse((time, index)));
+            }
+        }
+
+        while occurrences.len() < limit {
+            let Some(Reverse((occurrence_time, index))) = heap.pop() else {
+                break;
+            };
+            occurrences.push(EventOccurrence {
+                event_index: index,
+                event: &self.events[index],
+                occurrence_time,
+            });
+            if let Some(time) = series[index].next() {
+                heap.push(Reverse((time, index)));
+            }
+        }
+
+        Ok(occurrences)
+    }
+
+    /// Get the soonest active occurrence starting at or after `after`
+    ///
+    /// Recurring events are expanded lazily and only until they pass the
+    /// best candidate found so far, so long-running series are never
+    /// materialised. Cancelled events are skipped; ties are broken by event
+    /// index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event, Recurrence};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let mut cal = Calendar::new("Team");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Standup")
+    ///         .start("2025-11-01 09:00:00", "UTC")
+    ///         .duration_minutes(15)
+    ///         .recurrence(Recurrence::daily())
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let now = parse_datetime_with_tz("2025-11-05 12:00:00", tz).unwrap();
+    ///
+    /// let next = cal.next_event(now).unwrap().unwrap();
+    /// assert_eq!(next.occurrence_time, parse_datetime_with_tz("2025-11-06 09:00:00", tz).unwrap());
+    /// ```
+    pub fn next_event(&self, after: DateTime<Tz>) -> Result<Option<EventOccurrence<'_>>> {
+        let mut best: Option<EventOccurrence<'_>> = None;
+
+        for (index, event) in self.events.iter().enumerate() {
+            if !event.is_active() {
+                continue;
+            }
+            // Nothing later than the current best can win
+            let horizon = match best {
+                Some(ref b) => b.occurrence_time,
+                None => DateTime::<chrono::Utc>::MAX_UTC.with_timezone(&after.timezone()),
+            };
+            let next = event.occurrences_in_bounded(after, horizon).find(|dt| *dt >= after);
+            if let Some(occurrence_time) = next {
+                best = Some(EventOccurrence {
+                    event_index: index,
+                    event,
+                    occurrence_time,
+                });
+            }
+        }
+
+        Ok(best)
+    }
+
+    /// Get at most `max_total` occurrences within a date range, across all events
+    ///
+    /// Returns the earliest `max_total` occurrences in chronological order,
+    /// together with a flag that is `true` when more occurrences exist in the
+    /// range than were returned. Built on
+    /// [`first_occurrences_between`](Self::first_occurrences_between), so
+    /// large series are never fully materialised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event, Recurrence};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let mut cal = Calendar::new("Busy");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Standup")
+    ///         .start("2025-11-01 09:00:00", "UTC")
+    ///         .duration_minutes(15)
+    ///         .recurrence(Recurrence::daily().count(365))
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let start = parse_datetime_with_tz("2025-11-01 00:00:00", tz).unwrap();
+    /// let end = parse_datetime_with_tz("2026-11-01 00:00:00", tz).unwrap();
+    ///
+    /// let (page, truncated) = cal.events_between_limited(start, end, 20).unwrap();
+    /// assert_eq!(page.len(), 20);
+    /// assert!(truncated);
+    /// ```
+    pub fn events_between_limited(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        max_total: usize,
+    ) -> Result<(Vec<EventOccurrence<'_>>, bool)> {
+        // One extra occurrence is enough to detect truncation.
+        let mut occurrences =
+            self.first_occurrences_between(start, end, max_total.saturating_add(1))?;
+
+        let truncated = occurrences.len() > max_total;
+        occurrences.truncate(max_total);
+
+        Ok((occurrences, truncated))
+    }
+
+    /// Busy intervals in `[start, end)`, each widened by `pad` on both sides
+    ///
+    /// Occurrences of time-blocking events are padded, then overlapping or
+    /// touching intervals are merged and clipped to the window. Occurrences
+    /// just outside the window still count if their padding reaches into
+    /// it. The complement of the result is the free time that leaves at
+    /// least `pad` around every meeting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Duration, Event};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let mut cal = Calendar::new("Buffers");
+    /// cal.add_event(Event::builder().title("Sync").start("2025-11-03 10:00:00", "UTC").duration_hours(1).build().unwrap());
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let at = |s: &str| parse_datetime_with_tz(s, tz).unwrap();
+    ///
+    /// let busy = cal.padded_busy_intervals(at("2025-11-03 08:00:00"), at("2025-11-03 18:00:00"), Duration::minutes(15)).unwrap();
+    /// assert_eq!(busy, vec![(at("2025-11-03 09:45:00"), at("2025-11-03 11:15:00"))]);
+    /// ```
+    pub fn padded_busy_intervals(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        pad: Duration,
+    ) -> Result<Vec<(DateTime<Tz>, DateTime<Tz>)>> {
+        if start >= end {
+            return Err(EventixError::ValidationError(
+                "Start time must be before end time".to_string(),
+            ));
+        }
+        if pad < Duration::zero() {
+            return Err(EventixError::ValidationError("Padding cannot be negative".to_string()));
+        }
+
+        let mut intervals: Vec<(DateTime<Tz>, DateTime<Tz>)> = self
+            .events_between(start - pad, end + pad)?
+            .iter()
+            .filter(|occ| occ.event.blocks_time())
+            .map(|occ| ((occ.occurrence_time - pad).max(start), (occ.end_time() + pad).min(end)))
+            .filter(|(from, to)| from < to)
+            .collect();
+        intervals.sort();
+
+        let mut merged: Vec<(DateTime<Tz>, DateTime<Tz>)> = Vec::with_capacity(intervals.len());
+        for (from, to) in intervals {
+            match merged.last_mut() {
+                Some((_, last_end)) if from <= *last_end => *last_end = (*last_end).max(to),
+                _ => merged.push((from, to)),
+            }
+        }
+
+        Ok(merged)
+    }
+
+    /// Compute duration statistics over active occurrences in a date range
+    ///
+    /// Recurring events are expanded, so a daily 15-minute standup counts once
+    /// per day. Cancelled events are ignored. An empty range yields zeroed
+    /// statistics with `count == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Duration, Event};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let mut cal = Calendar::new("Hygiene");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Sync")
+    ///         .start("2025-11-03 09:00:00", "UTC")
+    ///         .duration_minutes(30)
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let start = parse_datetime_with_tz("2025-11-03 00:00:00", tz).unwrap();
+    /// let end = parse_datetime_with_tz("2025-11-04 00:00:00", tz).unwrap();
+    ///
+    /// let stats = cal.duration_stats(start, end).unwrap();
+    /// assert_eq!(stats.mean, Duration::minutes(30));
+    /// ```
+    pub fn duration_stats(&self, start: DateTime<Tz>, end: DateTime<Tz>) -> Result<DurationStats> {
+        let mut durations: Vec<Duration> = self
+            .events_between(start, end)?
+            .into_iter()
+            .filter(|o| o.event.is_active())
+            .map(|o| o.end_time() - o.occurrence_time)
+            .collect();
+
+        if durations.is_empty() {
+            return Ok(DurationStats {
+                count: 0,
+                total: Duration::zero(),
+                mean: Duration::zero(),
+                median: Duration::zero(),
+                min: Duration::zero(),
+                max: Duration::zero(),
+            });
+        }
+
+        durations.sort();
+        let count = durations.len();
+        let total = durations.iter().fold(Duration::zero(), |acc, d| acc + *d);
+        let mean = total / count as i32;
+        let median = if count.is_multiple_of(2) {
+            (durations[count / 2 - 1] + durations[count / 2]) / 2
+        } else {
+            durations[count / 2]
+        };
+
+        Ok(DurationStats {
+            count,
+            total,
+            mean,
+            median,
+            min: durations[0],
+            max: durations[count - 1],
+        })
+    }
+
+    /// Count the VEVENTs needed to export the range with recurrences expanded
+    ///
+    /// Each occurrence intersecting `[start, end]` counts as one VEVENT, which
+    /// is how servers that materialise recurring series account for them.
+    pub fn exported_vevent_count(&self, start: DateTime<Tz>, end: DateTime<Tz>) -> Result<usize> {
+        Ok(self.events_between(start, end)?.len())
+    }
+
+    /// Check whether the range fits within a downstream VEVENT budget
+    ///
+    /// See [`exported_vevent_count`](Self::exported_vevent_count).
+    pub fn fits_budget(
+        &self,
+        max_vevents: usize,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Result<bool> {
+        Ok(self.exported_vevent_count(start, end)? <= max_vevents)
+    }
+
+    /// Shorten recurring series until the range fits a VEVENT budget
+    ///
+    /// Non-recurring events are kept as-is. The remaining budget is filled
+    /// with the earliest recurring occurrences across all series, and each
+    /// series that loses occurrences gets an `until` just before its first
+    /// dropped occurrence (replacing any `count`). A series whose first
+    /// dropped occurrence is its own start has nothing left and is removed
+    /// from the calendar. Returns one line per trimmed or removed series
+    /// describing the change; an empty list means the calendar already fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the non-recurring events in the range
+    /// alone exceed the budget.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event, Recurrence};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let mut cal = Calendar::new("Feed");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Standup")
+    ///         .start("2025-11-01 09:00:00", "UTC")
+    ///         .duration_minutes(15)
+    ///         .recurrence(Recurrence::daily())
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let start = parse_datetime_with_tz("2025-11-01 00:00:00", tz).unwrap();
+    /// let end = parse_datetime_with_tz("2025-12-01 00:00:00", tz).unwrap();
+    ///
+    /// assert!(!cal.fits_budget(10, start, end).unwrap());
+    /// let trimmed = cal.trim_to_budget(10, start, end).unwrap();
+    /// assert_eq!(trimmed.len(), 1);
+    /// assert!(cal.fits_budget(10, start, end).unwrap());
+    /// ```
+    pub fn trim_to_budget(
+        &mut self,
+        max_vevents: usize,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Result<Vec<String>> {
+        // Per event: occurrences in range, occurrences kept, first dropped occurrence
+        let mut tally: Vec<(usize, usize, Option<DateTime<Tz>>)> =
+            vec![(0, 0, None); self.events.len()];
+        {
+            let occurrences = self.events_between(start, end)?;
+            if occurrences.len() <= max_vevents {
+                return Ok(Vec::new());
+            }
+
+            let fixed = occurrences.iter().filter(|o| o.event.recurrence.is_none()).count();
+            if fixed > max_vevents {
+                return Err(EventixError::ValidationError(format!(
+                    "{} non-recurring events in range exceed the budget of {}",
+                    fixed, max_vevents
+                )));
+            }
+
+            let mut remaining = max_vevents - fixed;
+            for o in occurrences.iter().filter(|o| o.event.recurrence.is_some()) {
+                let (total, kept, first_dropped) = &mut tally[o.event_index];
+                *total += 1;
+                if remaining > 0 && first_dropped.is_none() {
+                    remaining -= 1;
+                    *kept += 1;
+                } else if first_dropped.is_none() {
+                    *first_dropped = Some(o.occurrence_time);
+                }
+            }
+        }
+
+        let mut report = Vec::new();
+        let mut removed = vec![false; self.events.len()];
+        for ((event, (total, kept, first_dropped)), removed) in
+            self.events.iter_mut().zip(tally).zip(removed.iter_mut())
+        {
+            let (Some(first_dropped), Some(recurrence)) =
+                (first_dropped, event.recurrence.as_ref())
+            else {
+                continue;
+            };
+            let until = first_dropped - Duration::seconds(1);
+            // An `until` before the start would leave an invalid recurrence
+            if until < event.start_time {
+                *removed = true;
+                report.push(format!(
+                    "{}: removed, no occurrences before {} ({} of {} occurrences in range kept)",
+                    event.title,
+                    first_dropped.format("%Y-%m-%d %H:%M:%S %Z"),
+                    kept,
+                    total
+                ));
+                continue;
+            }
+            event.recurrence = Some(recurrence.ending_at(until));
+            report.push(format!(
+                "{}: recurrence now ends before {} ({} of {} occurrences in range kept)",
+                event.title,
+                first_dropped.format("%Y-%m-%d %H:%M:%S %Z"),
+                kept,
+                total
+            ));
+        }
+
+        let mut removed = removed.into_iter();
+        self.events.retain(|_| !removed.next().unwrap_or(false));
+
+        Ok(report)
+    }
+
+    /// Find the longest run of consecutive meeting-free days
+    ///
+    /// Walks each local day from `start` to `end` inclusive in `tz` and
+    /// returns `(first_day, last_day, length)` for the longest run of days
+    /// with no active occurrences. Occurrences spanning midnight occupy every
+    /// day they touch. The earliest run wins ties. If every day has a
+    /// meeting the result is `(start, start, 0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event, NaiveDate};
+    /// use eventix::timezone::parse_timezone;
+    ///
+    /// let mut cal = Calendar::new("Focus");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Planning")
+    ///         .start("2025-11-04 10:00:00", "UTC")
+    ///         .duration_hours(1)
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let start = NaiveDate::from_ymd_opt(2025, 11, 3).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2025, 11, 7).unwrap();
+    ///
+    /// let (first, last, days) = cal.longest_free_day_streak(start, end, tz).unwrap();
+    /// assert_eq!((first, last, days), (NaiveDate::from_ymd_opt(2025, 11, 5).unwrap(), end, 3));
+    /// ```
+    pub fn longest_free_day_streak(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        tz: Tz,
+    ) -> Result<(NaiveDate, NaiveDate, u32)> {
+        if start > end {
+            return Err(EventixError::ValidationError(
+                "Start date must be before or equal to end date".to_string(),
+            ));
+        }
+
+        let mut best = (start, start, 0);
+        let mut current: Option<(NaiveDate, u32)> = None;
+
+        for date in start.iter_days().take_while(|d| *d <= end) {
+            let (day_start, day_end) = local_day_window(date, tz)?;
+            let busy = self.events_between(day_start, day_end)?.iter().any(|o| o.event.is_active());
+
+            if busy {
+                current = None;
+                continue;
+            }
+
+            let (run_start, run_len) = match current {
+                Some((run_start, run_len)) => (run_start, run_len + 1),
+                None => (date, 1),
+            };
+            current = Some((run_start, run_len));
+            if run_len > best.2 {
+                best = (run_start, date, run_len);
+            }
+        }
+
+        Ok(best)
+    }
+
+    /// Split the calendar into `(past, future)` halves around `now`
+    ///
+    /// Non-recurring events go wholly to one side: past if they have ended
+    /// by `now`, future otherwise. Recurring series that straddle `now` are
+    /// split into two events: the past half is limited to the occurrences
+    /// that have ended, and the future half starts at the first occurrence
+    /// still in progress or upcoming, with any `count` reduced to match.
+    /// Occurrences skipped by the recurrence filter, exception dates, or
+    /// cancelled overrides never decide the split, and moved overrides count
+    /// with their new end. Each half keeps the overrides on its side of the
+    /// split. The future half of a split series takes the occurrence UID of
+    /// its first occurrence (see [`EventOccurrence::occurrence_uid`]) so it
+    /// is not confused with the original. Both calendars keep the name,
+    /// description, and timezone of this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event, Recurrence};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let mut cal = Calendar::new("Archive");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Standup")
+    ///         .start("2025-11-01 09:00:00", "UTC")
+    ///         .duration_minutes(15)
+    ///         .recurrence(Recurrence::daily().count(10))
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let now = parse_datetime_with_tz("2025-11-04 12:00:00", tz).unwrap();
+    ///
+    /// let (past, future) = cal.partition_by_time(now);
+    /// assert_eq!(past.event_count(), 1);
+    /// assert_eq!(future.event_count(), 1);
+    /// ```
+    pub fn partition_by_time(&self, now: DateTime<Tz>) -> (Calendar, Calendar) {
+        let empty = || Calendar {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            events: Vec::new(),
+            timezone: self.timezone,
+            url: self.url.clone(),
+            source: self.source.clone(),
+        };
+        let mut past = empty();
+        let mut future = empty();
+
+        for event in &self.events {
+            let Some(ref recurrence) = event.recurrence else {
+                if event.end_time <= now {
+                    past.add_event(event.clone());
+                } else {
+                    future.add_event(event.clone());
+                }
+                continue;
+            };
+
+            // Index into the raw series (COUNT counts skipped instances too)
+            // of the first live instance that has not ended by `now`
+            let mut any_ended = false;
+            let split =
+                recurrence.occurrences(event.start_time).enumerate().find(|&(_, original)| {
+                    match event.instance_end(original) {
+                        Some(end) if end > now => true,
+                        Some(_) => {
+                            any_ended = true;
+                            false
+                        }
+                        None => false,
+                    }
+                });
+
+            match split {
+                // Series has fully ended
+                None => past.add_event(event.clone()),
+                // Nothing has ended yet
+                Some(_) if !any_ended => future.add_event(event.clone()),
+                Some((index, first_future)) => {
+                    let ended = u32::try_from(index).unwrap_or(u32::MAX);
+
+                    let mut earlier = event.clone();
+                    earlier.recurrence = Some(recurrence.truncated(ended));
+                    earlier.overrides.retain(|original, _| *original < first_future);
+                    past.add_event(earlier);
+
+                    let mut later = event.clone();
+                    later.start_time = first_future;
+                    later.end_time = first_future + event.duration();
+                    later.recurrence = Some(recurrence.resumed_after(ended));
+                    later.overrides.retain(|original, _| *original >= first_future);
+                    later.uid =
+                        event.uid.as_deref().map(|uid| Event::instance_uid(uid, first_future));
+                    future.add_event(later);
+                }
+            }
+        }
+
+        (past, future)
+    }
+
+    /// Classify events as `(past, current, future)` relative to `now`
+    ///
+    /// Past events ended at or before `now`, current events span it, and
+    /// future events start after it. A recurring event is classified by its
+    /// next relevant occurrence (honouring exception dates and overrides):
+    /// current if one is in progress, future if one is upcoming, past if the
+    /// series is over or its filter skips every remaining date (see
+    /// [`MAX_CONSECUTIVE_SKIPS`](crate::event::MAX_CONSECUTIVE_SKIPS)). Unlike [`partition_by_time`](Self::partition_by_time),
+    /// nothing is split or cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let mut cal = Calendar::new("Dashboard");
+    /// for start in ["2025-11-04 08:00:00", "2025-11-04 11:30:00", "2025-11-04 15:00:00"] {
+    ///     cal.add_event(Event::builder().title(start).start(start, "UTC").duration_hours(1).build().unwrap());
+    /// }
+    ///
+    /// let now = parse_datetime_with_tz("2025-11-04 12:00:00", parse_timezone("UTC").unwrap()).unwrap();
+    /// let (past, current, future) = cal.classify_by_time(now);
+    /// assert_eq!((past.len(), current.len(), future.len()), (1, 1, 1));
+    /// ```
+    pub fn classify_by_time(&self, now: DateTime<Tz>) -> (Vec<&Event>, Vec<&Event>, Vec<&Event>) {
+        let horizon = DateTime::<chrono::Utc>::MAX_UTC.with_timezone(&now.timezone());
+        let (mut past, mut current, mut future) = (Vec::new(), Vec::new(), Vec::new());
+
+        for event in &self.events {
+            match event.occurrences_in_bounded(now, horizon).next() {
+                None => past.push(event),
+                Some(start) if start <= now => current.push(event),
+                Some(_) => future.push(event),
+            }
+        }
+
+        (past, current, future)
+    }
+
+    /// Reschedule overlapping events so none of them conflict
+    ///
+    /// Single (non-recurring) events starting in `[start, end)` are processed
+    /// in start order. An event that overlaps one already kept in place is
+    /// pushed to the earliest later time where it clears every other event by
+    /// at least `buffer`. Recurring events, and single events that start
+    /// before `start` but run into the window, are never moved and act as
+    /// fixed obstacles; events that do not block time (cancelled or
+    /// transparent) are ignored. An event that cannot fit before `end` is left where it
+    /// is. Returns the number of events moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Duration, Event, gap_validation};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let mut cal = Calendar::new("Conflicts");
+    /// for start in ["2025-11-03 09:00:00", "2025-11-03 09:30:00"] {
+    ///     cal.add_event(Event::builder().title("Call").start(start, "UTC").duration_hours(1).build().unwrap());
+    /// }
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let start = parse_datetime_with_tz("2025-11-03 08:00:00", tz).unwrap();
+    /// let end = parse_datetime_with_tz("2025-11-03 18:00:00", tz).unwrap();
+    ///
+    /// assert_eq!(cal.resolve_conflicts(start, end, Duration::minutes(10)).unwrap(), 1);
+    /// assert_eq!(cal.events[1].start_time, parse_datetime_with_tz("2025-11-03 10:10:00", tz).unwrap());
+    /// assert!(gap_validation::find_overlaps(&cal, start, end).unwrap().is_empty());
+    /// ```
+    pub fn resolve_conflicts(
+        &mut self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        buffer: Duration,
+    ) -> Result<usize> {
+        if start >= end {
+            return Err(EventixError::ValidationError(
+                "Start time must be before end time".to_string(),
+            ));
+        }
+        if buffer < Duration::zero() {
+            return Err(EventixError::ValidationError("Buffer cannot be negative".to_string()));
+        }
+
+        // Recurring occurrences, and single events already under way at
+        // `start`, stay put
+        let mut placed: Vec<(DateTime<Tz>, DateTime<Tz>)> = self
+            .events_between(start, end)?
+            .iter()
+            .filter(|occ| {
+                (occ.event.recurrence.is_some() || occ.occurrence_time < start)
+                    && occ.event.blocks_time()
+            })
+            .map(|occ| (occ.occurrence_time, occ.end_time()))
+            .collect();
+
+        let mut movable: Vec<usize> = (0..self.events.len())
+            .filter(|&i| {
+                let event = &self.events[i];
+                event.recurrence.is_none()
+                    && event.blocks_time()
+                    && event.start_time >= start
+                    && event.start_time < end
+            })
+            .collect();
+        movable.sort_by_key(|&i| self.events[i].start_time);
+
+        let mut moved = 0;
+        for index in movable {
+            let event = &self.events[index];
+            let duration = event.duration();
+            let overlaps = |s: DateTime<Tz>, pad: Duration| {
+                placed
+                    .iter()
+                    .filter(move |(p_start, p_end)| {
+                        *p_start < s + duration + pad && s < *p_end + pad
+                    })
+                    .map(|(_, p_end)| *p_end)
+                    .max()
+            };
+
+            if overlaps(event.start_time, Duration::zero()).is_none() {
+                placed.push((event.start_time, event.end_time));
+                continue;
+            }
+
+            // Hop past whichever interval blocks the candidate until one fits
+            let mut candidate = event.start_time;
+            while let Some(blocker_end) = overlaps(candidate, buffer) {
+                candidate = blocker_end + buffer;
+            }
+
+            if candidate + duration > end {
+                placed.push((event.start_time, event.end_time));
+                continue;
+            }
+
+            let tz = event.timezone;
+            let new_start = candidate.with_timezone(&tz);
+            self.events[index].reschedule(new_start, new_start + duration)?;
+            placed.push((candidate, candidate + duration));
+            moved += 1;
+        }
+
+        Ok(moved)
+    }
+
+    /// Titles of events that conflict with `event_uid` here but not in `before`
+    ///
+    /// `self` is the calendar after a change (typically a reschedule) and
+    /// `before` is a snapshot from before it. Overlaps with the event's
+    /// occurrences in `[start, end)` are compared between the two, and the
+    /// titles only present afterwards are returned in chronological order.
+    /// Only time-blocking events are considered on either side.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `start` is not before `end` or if no
+    /// event with `event_uid` exists in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{timezone::{parse_datetime_with_tz, parse_timezone}, Calendar, Event};
+    ///
+    /// let mut before = Calendar::new("Work");
+    /// before.add_event(Event::builder().title("Review").start("2025-11-03 09:00:00", "UTC").duration_hours(1).uid("review").build().unwrap());
+    /// before.add_event(Event::builder().title("Lunch").start("2025-11-03 12:00:00", "UTC").duration_hours(1).build().unwrap());
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let mut after = before.clone();
+    /// let new_start = parse_datetime_with_tz("2025-11-03 12:30:00", tz).unwrap();
+    /// after.events[0].reschedule(new_start, new_start + eventix::Duration::hours(1)).unwrap();
+    ///
+    /// let start = parse_datetime_with_tz("2025-11-03 00:00:00", tz).unwrap();
+    /// let end = parse_datetime_with_tz("2025-11-04 00:00:00", tz).unwrap();
+    /// assert_eq!(after.conflicts_introduced_by(&before, "review", start, end).unwrap(), vec!["Lunch"]);
+    /// ```
+    pub fn conflicts_introduced_by(
+        &self,
+        before: &Calendar,
+        event_uid: &str,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Result<Vec<String>> {
+        if start >= end {
+            return Err(EventixError::ValidationError(
+                "Start time must be before end time".to_string(),
+            ));
+        }
+        if !self.events.iter().any(|e| e.uid.as_deref() == Some(event_uid)) {
+            return Err(EventixError::ValidationError(format!(
+                "No event with UID '{}'",
+                event_uid
+            )));
+        }
+
+        let previous = before.conflicting_titles(event_uid, start, end)?;
+        let mut introduced: Vec<String> = Vec::new();
+        for title in self.conflicting_titles(event_uid, start, end)? {
+            if !previous.contains(&title) && !introduced.contains(&title) {
+                introduced.push(title);
+            }
+        }
+        Ok(introduced)
+    }
+
+    /// Titles of blocking occurrences overlapping any occurrence of `event_uid`
+    fn conflicting_titles(
+        &self,
+        event_uid: &str,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Result<Vec<String>> {
+        let occurrences = self.events_between(start, end)?;
+        let (target, others): (Vec<_>, Vec<_>) = occurrences
+            .iter()
+            .filter(|occ| occ.event.blocks_time())
+            .partition(|occ| occ.event.uid.as_deref() == Some(event_uid));
+
+        Ok(others
+            .into_iter()
+            .filter(|other| {
+                target.iter().any(|t| {
+                    t.occurrence_time < other.end_time() && other.occurrence_time < t.end_time()
+                })
+            })
+            .map(|other| other.title().to_string())
+            .collect())
+    }
+
+    /// Free time immediately following an event, up to the next busy period
+    ///
+    /// `event` is matched against UIDs first, then titles. The first
+    /// occurrence of that event in `[start, end)` is used, and the gap runs
+    /// from its end until the next time-blocking occurrence (or `end`).
+    /// Returns `None` when the event has no occurrence in the window or
+    /// something else is already scheduled right when it ends.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `start` is not before `end` or if no
+    /// event matches `event`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{timezone::{parse_datetime_with_tz, parse_timezone}, Calendar, Event};
+    ///
+    /// let mut cal = Calendar::new("Work");
+    /// cal.add_event(Event::builder().title("Review").start("2025-11-03 09:00:00", "UTC").duration_hours(1).build().unwrap());
+    /// cal.add_event(Event::builder().title("Lunch").start("2025-11-03 12:00:00", "UTC").duration_hours(1).build().unwrap());
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let start = parse_datetime_with_tz("2025-11-03 00:00:00", tz).unwrap();
+    /// let end = parse_datetime_with_tz("2025-11-04 00:00:00", tz).unwrap();
+    ///
+    /// let gap = cal.gap_after("Review", start, end).unwrap().unwrap();
+    /// assert_eq!(gap.duration_hours(), 2);
+    /// ```
+    pub fn gap_after(
+        &self,
+        event: &str,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Result<Option<TimeGap>> {
+        let Some((_, occurrence_end)) = self.first_occurrence_of(event, start, end)? else {
+            return Ok(None);
+        };
+        if occurrence_end >= end {
+            return Ok(None);
+        }
+        let gaps = find_gaps(self, occurrence_end, end, Duration::zero())?;
+        Ok(gaps.into_iter().next().filter(|gap| gap.start == occurrence_end))
+    }
+
+    /// Free time immediately preceding an event, back to the previous busy period
+    ///
+    /// The mirror image of [`gap_after`](Self::gap_after): the gap ends at
+    /// the start of the event's first occurrence in `[start, end)` and begins
+    /// at the end of the previous time-blocking occurrence (or `start`).
+    pub fn gap_before(
+        &self,
+        event: &str,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Result<Option<TimeGap>> {
+        let Some((occurrence_start, _)) = self.first_occurrence_of(event, start, end)? else {
+            return Ok(None);
+        };
+        if occurrence_start <= start {
+            return Ok(None);
+        }
+        let gaps = find_gaps(self, start, occurrence_start, Duration::zero())?;
+        Ok(gaps.into_iter().last().filter(|gap| gap.end == occurrence_start))
+    }
+
+    /// Bounds of the first occurrence in `[start, end)` of the event with
+    /// UID `event`, or failing that, title `event`
+    fn first_occurrence_of(
+        &self,
+        event: &str,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Result<Option<(DateTime<Tz>, DateTime<Tz>)>> {
+        if start >= end {
+            return Err(EventixError::ValidationError(
+                "Start time must be before end time".to_string(),
+            ));
+        }
+        let matches_uid = self.events.iter().any(|e| e.uid.as_deref() == Some(event));
+        if !matches_uid && !self.events.iter().any(|e| e.title == event) {
+            return Err(EventixError::ValidationError(format!(
+                "No event with UID or title '{}'",
+                event
+            )));
+        }
+
+        let mut occurrences = self.events_between(start, end)?;
+        occurrences.retain(|occ| {
+            occ.occurrence_time < end
+                && if matches_uid {
+                    occ.event.uid.as_deref() == Some(event)
+                } else {
+                    occ.event.title == event
+                }
+        });
+        Ok(occurrences
+            .iter()
+            .min_by_key(|occ| occ.occurrence_time)
+            .map(|occ| (occ.occurrence_time, occ.end_time())))
+    }
+
+    /// Split this calendar into one calendar per event timezone
+    ///
+    /// Each sub-calendar is named `"<name> (<timezone>)"`, keeps this
+    /// calendar's description, uses its key as the default timezone, and
+    /// holds full copies of the events whose `timezone` matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event};
+    ///
+    /// let mut cal = Calendar::new("Global");
+    /// for tz in ["America/New_York", "Asia/Tokyo"] {
+    ///     cal.add_event(
+    ///         Event::builder()
+    ///             .title("Sync")
+    ///             .start("2025-11-03 09:00:00", tz)
+    ///             .duration_hours(1)
+    ///             .build()
+    ///             .unwrap(),
+    ///     );
+    /// }
+    ///
+    /// let regions = cal.split_by_timezone();
+    /// assert_eq!(regions.len(), 2);
+    /// assert_eq!(regions[&chrono_tz::Asia::Tokyo].name, "Global (Asia/Tokyo)");
+    /// ```
+    pub fn split_by_timezone(&self) -> HashMap<Tz, Calendar> {
+        let mut regions: HashMap<Tz, Calendar> = HashMap::new();
+        for event in &self.events {
+            regions
+                .entry(event.timezone)
+                .or_insert_with(|| Calendar {
+                    name: format!("{} ({})", self.name, event.timezone.name()),
+                    description: self.description.clone(),
+                    events: Vec::new(),
+                    timezone: Some(event.timezone),
+                    url: self.url.clone(),
+                    source: self.source.clone(),
+                })
+                .add_event(event.clone());
+        }
+        regions
+    }
+
     /// Get all events occurring on a specific date
+    ///
+    /// The day is the half-open local range `[00:00, next day 00:00)` in
+    /// `date`'s timezone, so the final second of the day is covered, an
+    /// event starting at the following midnight is excluded, and DST days
+    /// of 23 or 25 hours are handled without special cases.
     pub fn events_on_date(&self, date: DateTime<Tz>) -> Result<Vec<EventOccurrence<'_>>> {
         let (start_dt, end_dt) = local_day_window(date.date_naive(), date.timezone())?;
         self.events_between(start_dt, end_dt)
     }
 
+    /// Get all occurrences on today's local date in `tz`
+    ///
+    /// "Today" is taken from the system clock; see
+    /// [`events_on_date`](Self::events_on_date) for the window semantics.
+    pub fn events_today(&self, tz: Tz) -> Result<Vec<EventOccurrence<'_>>> {
+        self.events_today_with_clock(tz, &SystemClock)
+    }
+
+    /// Like [`events_today`](Self::events_today), reading "now" from `clock`
+    pub fn events_today_with_clock(
+        &self,
+        tz: Tz,
+        clock: &impl Clock,
+    ) -> Result<Vec<EventOccurrence<'_>>> {
+        self.events_on_date(clock.now().with_timezone(&tz))
+    }
+
+    /// Get all occurrences in the current local week in `tz`
+    ///
+    /// The week is the seven local days starting at midnight on the most
+    /// recent `week_start` (today included), taken from the system clock.
+    pub fn events_this_week(
+        &self,
+        tz: Tz,
+        week_start: Weekday,
+    ) -> Result<Vec<EventOccurrence<'_>>> {
+        self.events_this_week_with_clock(tz, week_start, &SystemClock)
+    }
+
+    /// Like [`events_this_week`](Self::events_this_week), reading "now" from `clock`
+    pub fn events_this_week_with_clock(
+        &self,
+        tz: Tz,
+        week_start: Weekday,
+        clock: &impl Clock,
+    ) -> Result<Vec<EventOccurrence<'_>>> {
+        self.events_in_week_of(clock.now().with_timezone(&tz), week_start)
+    }
+
+    /// Occurrences in the `week_start`-based local week containing `date`
+    fn events_in_week_of(
+        &self,
+        date: DateTime<Tz>,
+        week_start: Weekday,
+    ) -> Result<Vec<EventOccurrence<'_>>> {
+        let today = date.date_naive();
+        let offset =
+            (7 + today.weekday().num_days_from_monday() - week_start.num_days_from_monday()) % 7;
+        let first_day = today - chrono::Days::new(offset as u64);
+        let last_day = first_day + chrono::Days::new(6);
+
+        let (start_dt, _) = local_day_window(first_day, date.timezone())?;
+        let (_, end_dt) = local_day_window(last_day, date.timezone())?;
+        self.events_between(start_dt, end_dt)
+    }
+
     /// Create a lazy iterator over calendar days starting from the given date.
     ///
     /// Each yielded item is a [`crate::Result`] containing a [`crate::DayView`]
@@ -242,26 +1481,193 @@ impl Calendar {
 
     /// Create a lazy iterator over ISO weeks moving backward in time.
     ///
-    /// Each yielded item is a [`crate::Result`] containing a contiguous
-    /// Monday-Sunday block.
-    pub fn weeks_back(&self, start: DateTime<Tz>) -> WeekIterator<'_> {
-        WeekIterator::backward(self, start)
-    }
+    /// Each yielded item is a [`crate::Result`] containing a contiguous
+    /// Monday-Sunday block.
+    pub fn weeks_back(&self, start: DateTime<Tz>) -> WeekIterator<'_> {
+        WeekIterator::backward(self, start)
+    }
+
+    /// Produce a one-line, ready-to-print overview of each event
+    ///
+    /// Each line combines the title, the local start time, and either the
+    /// recurrence description (see [`Recurrence::describe`]) or the date of a
+    /// one-off event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event, Recurrence};
+    ///
+    /// let mut cal = Calendar::new("Team");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Standup")
+    ///         .start("2025-11-03 09:00:00", "UTC")
+    ///         .duration_minutes(15)
+    ///         .recurrence(Recurrence::daily().count(20))
+    ///         .skip_weekends(true)
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// assert_eq!(cal.summary_lines(), vec!["Standup — 09:00 — Daily, 20 times, skipping weekends"]);
+    /// ```
+    pub fn summary_lines(&self) -> Vec<String> {
+        self.events
+            .iter()
+            .map(|event| {
+                let schedule = match event.recurrence {
+                    Some(ref recurrence) => {
+                        let mut description = recurrence.describe();
+                        if event.recurrence_filter.as_ref().is_some_and(|f| f.skips_weekends()) {
+                            description.push_str(", skipping weekends");
+                        }
+                        description
+                    }
+                    None => format!("Once on {}", event.start_time.format("%Y-%m-%d")),
+                };
+                format!("{} — {} — {}", event.title, event.start_time.format("%H:%M"), schedule)
+            })
+            .collect()
+    }
+
+    /// Report events whose `timezone` disagrees with their start time
+    ///
+    /// ICS export writes `TZID` from [`Event::timezone`], so an event whose
+    /// field was changed after construction would be exported with the wrong
+    /// zone. Returns one message per mismatched event, naming it by title;
+    /// an empty list means the calendar is consistent.
+    ///
+    /// The end time is not checked: RFC 5545 allows `DTEND` to use a
+    /// different `TZID` than `DTSTART`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event};
+    ///
+    /// let mut event = Event::builder()
+    ///     .title("Call")
+    ///     .start("2025-11-03 09:00:00", "Asia/Tokyo")
+    ///     .duration_hours(1)
+    ///     .build()
+    ///     .unwrap();
+    /// event.timezone = chrono_tz::UTC;
+    ///
+    /// let cal: Calendar = vec![event].into_iter().collect();
+    /// assert_eq!(
+    ///     cal.timezone_consistency_report(),
+    ///     vec!["Event 'Call' has timezone UTC but its start time is in Asia/Tokyo"]
+    /// );
+    /// ```
+    pub fn timezone_consistency_report(&self) -> Vec<String> {
+        self.events
+            .iter()
+            .filter_map(|event| {
+                let start_tz = event.start_time.timezone();
+                if event.timezone == start_tz {
+                    return None;
+                }
+                Some(format!(
+                    "Event '{}' has timezone {} but its start time is in {}",
+                    event.title,
+                    event.timezone.name(),
+                    start_tz.name()
+                ))
+            })
+            .collect()
+    }
+
+    /// Get the number of events in the calendar
+    pub fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Clear all events from the calendar
+    pub fn clear_events(&mut self) {
+        self.events.clear();
+    }
+
+    /// Compare this calendar against a newer version of it
+    ///
+    /// Events are matched by UID. An event whose UID only appears in `other`
+    /// is added, one only in `self` is removed, and one in both whose fields
+    /// differ is modified. Events without a UID cannot be tracked across
+    /// versions, so they are matched by full equality instead and show up as
+    /// removed/added when anything about them changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event};
+    ///
+    /// let event = Event::builder()
+    ///     .title("Standup")
+    ///     .start("2025-11-03 09:00:00", "UTC")
+    ///     .duration_minutes(15)
+    ///     .uid("standup@example.com")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut before = Calendar::new("Work");
+    /// before.add_event(event.clone());
+    ///
+    /// let mut after = Calendar::new("Work");
+    /// let mut renamed = event;
+    /// renamed.title = "Daily Standup".to_string();
+    /// after.add_event(renamed);
+    ///
+    /// let diff = before.diff(&after);
+    /// assert_eq!(diff.modified.len(), 1);
+    /// assert!(diff.added.is_empty() && diff.removed.is_empty());
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a Calendar) -> CalendarDiff<'a> {
+        let ours: HashMap<&str, &Event> =
+            self.events.iter().filter_map(|e| Some((e.uid.as_deref()?, e))).collect();
+        let theirs: HashMap<&str, &Event> =
+            other.events.iter().filter_map(|e| Some((e.uid.as_deref()?, e))).collect();
+
+        let mut diff = CalendarDiff::default();
+
+        for event in &self.events {
+            match event.uid.as_deref() {
+                Some(uid) => match theirs.get(uid) {
+                    Some(&newer) if newer != event => diff.modified.push((event, newer)),
+                    Some(_) => {}
+                    None => diff.removed.push(event),
+                },
+                None => {
+                    if !other.events.iter().any(|e| e.uid.is_none() && e == event) {
+                        diff.removed.push(event);
+                    }
+                }
+            }
+        }
 
-    /// Get the number of events in the calendar
-    pub fn event_count(&self) -> usize {
-        self.events.len()
-    }
+        for event in &other.events {
+            let added = match event.uid.as_deref() {
+                Some(uid) => !ours.contains_key(uid),
+                None => !self.events.iter().any(|e| e.uid.is_none() && e == event),
+            };
+            if added {
+                diff.added.push(event);
+            }
+        }
 
-    /// Clear all events from the calendar
-    pub fn clear_events(&mut self) {
-        self.events.clear();
+        diff
     }
 
     /// Export calendar to JSON
     ///
-    /// Includes recurrence rules and exception dates for full round-trip
-    /// fidelity with [`from_json()`](Self::from_json).
+    /// Includes recurrence rules, exception dates, and the event metadata
+    /// (sequence, timestamps, categories, priority, transparency, class,
+    /// attachments, reminders, language, HTML description, and `X-`
+    /// properties) for a round-trip with [`from_json()`](Self::from_json).
+    /// Fields left at their default are omitted.
+    ///
+    /// Not included: recurrence filters, per-occurrence overrides, an
+    /// unexpandable imported `RRULE`, raw ICS sub-components, and the
+    /// calendar's `url` and `source`.
     pub fn to_json(&self) -> Result<String> {
         let json_val = serde_json::json!({
             "name": self.name,
@@ -286,6 +1692,42 @@ impl Calendar {
                         e.exdates.iter().map(|d| d.to_rfc3339()).collect::<Vec<_>>()
                     );
                 }
+                if !e.reminders.is_empty() {
+                    ev["reminders"] = serde_json::json!(e.reminders);
+                }
+                if let Some(ref html) = e.description_html {
+                    ev["description_html"] = serde_json::json!(html);
+                }
+                if let Some(ref language) = e.language {
+                    ev["language"] = serde_json::json!(language);
+                }
+                if e.sequence != 0 {
+                    ev["sequence"] = serde_json::json!(e.sequence);
+                }
+                if let Some(created) = e.created {
+                    ev["created"] = serde_json::json!(created);
+                }
+                if let Some(last_modified) = e.last_modified {
+                    ev["last_modified"] = serde_json::json!(last_modified);
+                }
+                if !e.categories.is_empty() {
+                    ev["categories"] = serde_json::json!(e.categories);
+                }
+                if !e.attachments.is_empty() {
+                    ev["attachments"] = serde_json::json!(e.attachments);
+                }
+                if let Some(priority) = e.priority {
+                    ev["priority"] = serde_json::json!(priority);
+                }
+                if e.transparency != Transparency::default() {
+                    ev["transparency"] = serde_json::json!(e.transparency);
+                }
+                if e.class != Classification::default() {
+                    ev["class"] = serde_json::json!(e.class);
+                }
+                if !e.extra_properties.is_empty() {
+                    ev["extra_properties"] = serde_json::json!(e.extra_properties);
+                }
                 ev
             }).collect::<Vec<_>>(),
             "timezone": self.timezone.map(|tz| tz.name()),
@@ -320,6 +1762,8 @@ impl Calendar {
             description,
             events: Vec::new(),
             timezone,
+            url: None,
+            source: None,
         };
 
         if let Some(events_array) = value["events"].as_array() {
@@ -355,6 +1799,8 @@ impl Calendar {
                 let event = Event {
                     title: title.to_string(),
                     description: event_val["description"].as_str().map(|s| s.to_string()),
+                    description_html: json_event_field(event_val, "description_html")?,
+                    language: json_event_field(event_val, "language")?,
                     start_time: start_time_tz,
                     end_time: end_time_tz,
                     timezone: tz,
@@ -369,6 +1815,7 @@ impl Calendar {
                         None => None,
                     },
                     recurrence_filter: None,
+                    raw_rrule: None,
                     exdates: match event_val["exdates"].as_array() {
                         Some(arr) => {
                             let mut dates = Vec::with_capacity(arr.len());
@@ -396,6 +1843,23 @@ impl Calendar {
                             ))
                         })?,
                     },
+                    sequence: json_event_field(event_val, "sequence")?,
+                    created: json_event_field(event_val, "created")?,
+                    last_modified: json_event_field(event_val, "last_modified")?,
+                    categories: json_event_field(event_val, "categories")?,
+                    attachments: json_event_field(event_val, "attachments")?,
+                    priority: json_event_field(event_val, "priority")?,
+                    transparency: json_event_field(event_val, "transparency")?,
+                    class: json_event_field(event_val, "class")?,
+                    reminders: match event_val.get("reminders") {
+                        None => Vec::new(),
+                        Some(v) => serde_json::from_value(v.clone()).map_err(|e| {
+                            EventixError::Other(format!("Invalid reminders '{}': {}", v, e))
+                        })?,
+                    },
+                    extra_properties: json_event_field(event_val, "extra_properties")?,
+                    raw_subcomponents: Vec::new(),
+                    overrides: HashMap::new(),
                 };
 
                 calendar.add_event(event);
@@ -406,6 +1870,141 @@ impl Calendar {
     }
 }
 
+/// Builder for creating calendars with a fluent API
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, Event};
+///
+/// let event = Event::builder()
+///     .title("Standup")
+///     .start("2025-11-03 09:00:00", "UTC")
+///     .duration_minutes(15)
+///     .build()
+///     .unwrap();
+///
+/// let cal = Calendar::builder()
+///     .name("Team")
+///     .timezone("Europe/Berlin")
+///     .event(event)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(cal.name, "Team");
+/// assert_eq!(cal.event_count(), 1);
+/// ```
+pub struct CalendarBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    timezone: Option<Tz>,
+    events: Vec<Event>,
+    /// First parsing error encountered during builder chain
+    parse_error: Option<EventixError>,
+}
+
+impl CalendarBuilder {
+    /// Create a new calendar builder
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            description: None,
+            timezone: None,
+            events: Vec::new(),
+            parse_error: None,
+        }
+    }
+
+    /// Set the calendar name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the calendar description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the default timezone by IANA name (e.g. `"America/New_York"`)
+    pub fn timezone(mut self, timezone: &str) -> Self {
+        match crate::timezone::parse_timezone(timezone) {
+            Ok(tz) => self.timezone = Some(tz),
+            Err(e) => {
+                if self.parse_error.is_none() {
+                    self.parse_error = Some(e);
+                }
+            }
+        }
+        self
+    }
+
+    /// Add an event to the calendar
+    pub fn event(mut self, event: Event) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Add multiple events to the calendar
+    pub fn events(mut self, events: Vec<Event>) -> Self {
+        self.events.extend(events);
+        self
+    }
+
+    /// Build the calendar
+    ///
+    /// Fails if no name was given or the timezone could not be parsed.
+    pub fn build(self) -> Result<Calendar> {
+        if let Some(err) = self.parse_error {
+            return Err(err);
+        }
+
+        let name = self.name.ok_or_else(|| {
+            EventixError::ValidationError("Calendar name is required".to_string())
+        })?;
+
+        let mut calendar = Calendar::new(name);
+        calendar.description = self.description;
+        calendar.timezone = self.timezone;
+        calendar.events = self.events;
+        Ok(calendar)
+    }
+}
+
+impl Default for CalendarBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromIterator<Event> for Calendar {
+    /// Collect events into a calendar named `"Calendar"`
+    fn from_iter<I: IntoIterator<Item = Event>>(iter: I) -> Self {
+        let mut calendar = Calendar::new("Calendar");
+        calendar.events.extend(iter);
+        calendar
+    }
+}
+
+impl IntoIterator for Calendar {
+    type Item = Event;
+    type IntoIter = std::vec::IntoIter<Event>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Calendar {
+    type Item = &'a Event;
+    type IntoIter = std::slice::Iter<'a, Event>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.iter()
+    }
+}
+
 /// Represents a specific occurrence of an event (useful for recurring events)
 #[derive(Debug, Clone)]
 pub struct EventOccurrence<'a> {
@@ -422,18 +2021,114 @@ pub struct EventOccurrence<'a> {
 impl<'a> EventOccurrence<'a> {
     /// Get the end time of this occurrence
     pub fn end_time(&self) -> DateTime<Tz> {
-        let duration = self.event.duration();
-        self.occurrence_time + duration
+        self.event.occurrence_end(self.occurrence_time)
     }
 
     /// Get the title of this occurrence
     pub fn title(&self) -> &str {
-        &self.event.title
+        self.override_info()
+            .and_then(|o| o.title.as_deref())
+            .unwrap_or(&self.event.title)
     }
 
     /// Get the description of this occurrence
     pub fn description(&self) -> Option<&str> {
-        self.event.description.as_deref()
+        self.override_info()
+            .and_then(|o| o.description.as_deref())
+            .or(self.event.description.as_deref())
+    }
+
+    /// Get the location of this occurrence
+    pub fn location(&self) -> Option<&str> {
+        self.override_info()
+            .and_then(|o| o.location.as_deref())
+            .or(self.event.location.as_deref())
+    }
+
+    /// Stable identifier for this occurrence, e.g. `meeting@x_20251103T140000Z`
+    ///
+    /// Joins the event UID and the occurrence's original start in UTC basic
+    /// format, matching the `RECURRENCE-ID` convention, so an occurrence
+    /// moved by an override keeps the ID of the slot it replaces. Events
+    /// without a UID use `event-{event_index}`, which is only stable while
+    /// the calendar's event order is unchanged.
+    pub fn occurrence_uid(&self) -> String {
+        let uid = match self.event.uid {
+            Some(ref uid) => uid.clone(),
+            None => format!("event-{}", self.event_index),
+        };
+        self.event.occurrence_uid_for(&uid, self.occurrence_time)
+    }
+
+    /// The override that moved this occurrence, if any
+    fn override_info(&self) -> Option<&'a EventOverride> {
+        self.event.override_for(self.occurrence_time)
+    }
+}
+
+/// Differences between two versions of a calendar
+///
+/// Returned by [`Calendar::diff`]. Events are listed in calendar order.
+#[derive(Debug, Clone, Default)]
+pub struct CalendarDiff<'a> {
+    /// Events present only in the newer calendar
+    pub added: Vec<&'a Event>,
+    /// Events present only in the older calendar
+    pub removed: Vec<&'a Event>,
+    /// Events sharing a UID whose fields changed, as (old, new)
+    pub modified: Vec<(&'a Event, &'a Event)>,
+}
+
+impl CalendarDiff<'_> {
+    /// Returns true if the two calendars have the same events
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// Summary statistics over occurrence durations
+///
+/// Returned by [`Calendar::duration_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationStats {
+    /// Number of occurrences analyzed
+    pub count: usize,
+    /// Sum of all occurrence durations
+    pub total: Duration,
+    /// Average occurrence duration
+    pub mean: Duration,
+    /// Median occurrence duration (average of the two middle values for even counts)
+    pub median: Duration,
+    /// Shortest occurrence duration
+    pub min: Duration,
+    /// Longest occurrence duration
+    pub max: Duration,
+}
+
+/// Expand one event's occurrences, skipping series that can't reach the window
+fn expand_event(
+    event: &Event,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    max_per_event: usize,
+) -> Result<Vec<DateTime<Tz>>> {
+    if !event.may_intersect(start, end) {
+        return Ok(Vec::new());
+    }
+    #[cfg(test)]
+    tests::EXPANSIONS.with(|count| count.set(count.get() + 1));
+    event.occurrences_between(start, end, max_per_event)
+}
+
+/// Deserialize an optional event field from JSON, defaulting when absent
+fn json_event_field<T: serde::de::DeserializeOwned + Default>(
+    event_val: &serde_json::Value,
+    key: &str,
+) -> Result<T> {
+    match event_val.get(key) {
+        None => Ok(T::default()),
+        Some(v) => serde_json::from_value(v.clone())
+            .map_err(|e| EventixError::Other(format!("Invalid {} '{}': {}", key, v, e))),
     }
 }
 
@@ -535,22 +2230,564 @@ fn json_to_recurrence(val: &serde_json::Value, tz: Tz) -> crate::error::Result<R
             rec = rec.weekdays(weekdays);
         }
     }
-    Ok(rec)
-}
+    Ok(rec)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::Event;
+
+    thread_local! {
+        /// Number of events handed to full expansion on this thread
+        pub(super) static EXPANSIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    #[test]
+    fn test_calendar_builder() {
+        let standup = Event::builder()
+            .title("Standup")
+            .start("2025-11-03 09:00:00", "America/New_York")
+            .duration_minutes(15)
+            .build()
+            .unwrap();
+        let review = Event::builder()
+            .title("Review")
+            .start("2025-11-03 15:00:00", "America/New_York")
+            .duration_hours(1)
+            .build()
+            .unwrap();
+
+        let cal = Calendar::builder()
+            .name("Team")
+            .description("Team meetings")
+            .timezone("America/New_York")
+            .event(standup)
+            .events(vec![review])
+            .build()
+            .unwrap();
+
+        assert_eq!(cal.name, "Team");
+        assert_eq!(cal.description.as_deref(), Some("Team meetings"));
+        assert_eq!(cal.timezone, Some(chrono_tz::America::New_York));
+        let titles: Vec<_> = cal.events.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, vec!["Standup", "Review"]);
+
+        assert!(Calendar::builder().build().is_err());
+        assert!(Calendar::builder().name("X").timezone("Not/AZone").build().is_err());
+    }
+
+    #[test]
+    fn test_timezone_consistency_report_flags_mismatch() {
+        let tokyo = crate::timezone::parse_timezone("Asia/Tokyo").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-11-03 09:00:00", tokyo).unwrap();
+        let mut mismatched = Event::builder()
+            .title("Tokyo call")
+            .start_datetime(start)
+            .end_datetime(start + Duration::hours(1))
+            .build()
+            .unwrap();
+        mismatched.timezone = chrono_tz::UTC;
+        let consistent = Event::builder()
+            .title("Local call")
+            .start_datetime(start)
+            .end_datetime(start + Duration::hours(1))
+            .build()
+            .unwrap();
+
+        let cal: Calendar = vec![mismatched, consistent].into_iter().collect();
+        let report = cal.timezone_consistency_report();
+        assert_eq!(report.len(), 1);
+        assert!(report[0].contains("'Tokyo call'"));
+        assert!(report[0].contains("Asia/Tokyo"));
+    }
+
+    #[test]
+    fn test_timezone_consistency_report_allows_different_end_tzid() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:flight@example.com
+SUMMARY:Flight
+DTSTART;TZID=America/New_York:20251103T080000
+DTEND;TZID=America/Los_Angeles:20251103T110000
+END:VEVENT
+END:VCALENDAR";
+
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        assert_ne!(cal.events[0].end_time.timezone(), cal.events[0].timezone);
+        assert!(cal.timezone_consistency_report().is_empty());
+    }
+
+    #[test]
+    fn test_gap_after_and_before_event() {
+        let mut cal = Calendar::new("Day");
+        for (title, start) in [("Standup", "09:00:00"), ("Design", "11:00:00")] {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start(&format!("2025-11-03 {}", start), "UTC")
+                    .duration_hours(1)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+        let (start, end) = (at("2025-11-03 08:00:00"), at("2025-11-03 18:00:00"));
+
+        let after = cal.gap_after("Standup", start, end).unwrap().unwrap();
+        assert_eq!(
+            (after.start, after.end),
+            (at("2025-11-03 10:00:00"), at("2025-11-03 11:00:00"))
+        );
+        assert_eq!(after.after_event.as_deref(), Some("Design"));
+
+        let before = cal.gap_before("Design", start, end).unwrap().unwrap();
+        assert_eq!((before.start, before.end), (after.start, after.end));
+
+        // The last event's gap runs to the end of the window
+        let tail = cal.gap_after("Design", start, end).unwrap().unwrap();
+        assert_eq!((tail.start, tail.end), (at("2025-11-03 12:00:00"), end));
+
+        assert!(cal.gap_after("Nope", start, end).is_err());
+    }
+
+    #[test]
+    fn test_calendar_creation() {
+        let cal = Calendar::new("Test Calendar").description("A test calendar");
+
+        assert_eq!(cal.name, "Test Calendar");
+        assert_eq!(cal.description, Some("A test calendar".to_string()));
+        assert_eq!(cal.event_count(), 0);
+    }
+
+    fn titled(title: &str, start: &str) -> Event {
+        Event::builder()
+            .title(title)
+            .start(start, "UTC")
+            .duration_hours(1)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_calendar_diff_buckets() {
+        let with_uid = |title: &str, uid: &str| {
+            Event::builder()
+                .title(title)
+                .start("2025-11-03 09:00:00", "UTC")
+                .duration_hours(1)
+                .uid(uid)
+                .build()
+                .unwrap()
+        };
+
+        let base: Calendar = vec![
+            with_uid("Standup", "standup"),
+            with_uid("Retro", "retro"),
+            with_uid("Planning", "planning"),
+        ]
+        .into_iter()
+        .collect();
+        let newer: Calendar = vec![
+            with_uid("Standup", "standup"),
+            with_uid("Sprint Planning", "planning"),
+            with_uid("Demo", "demo"),
+        ]
+        .into_iter()
+        .collect();
+
+        let diff = base.diff(&newer);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].title, "Demo");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].title, "Retro");
+        assert_eq!(diff.modified.len(), 1);
+        assert_eq!(diff.modified[0].0.title, "Planning");
+        assert_eq!(diff.modified[0].1.title, "Sprint Planning");
+
+        assert!(base.diff(&base).is_empty());
+    }
+
+    #[test]
+    fn test_resolve_conflicts_reflows_overlapping_meetings() {
+        let mut cal: Calendar = vec![
+            titled("Standup", "2025-11-03 09:00:00"),
+            titled("Design", "2025-11-03 09:30:00"),
+            titled("Interview", "2025-11-03 09:45:00"),
+        ]
+        .into_iter()
+        .collect();
+        let mut dropped = titled("Dropped", "2025-11-03 09:15:00");
+        dropped.cancel();
+        cal.add_event(dropped);
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+        let (start, end) = (at("2025-11-03 08:00:00"), at("2025-11-03 18:00:00"));
+
+        let moved = cal.resolve_conflicts(start, end, Duration::minutes(5)).unwrap();
+        assert_eq!(moved, 2);
+
+        let starts: Vec<_> = cal.events.iter().map(|e| e.start_time).collect();
+        assert_eq!(
+            starts,
+            vec![
+                at("2025-11-03 09:00:00"),
+                at("2025-11-03 10:05:00"),
+                at("2025-11-03 11:10:00"),
+                at("2025-11-03 09:15:00"),
+            ]
+        );
+        assert!(crate::gap_validation::find_overlaps(&cal, start, end).unwrap().is_empty());
+        assert_eq!(cal.resolve_conflicts(start, end, Duration::minutes(5)).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_resolve_conflicts_treats_event_under_way_at_start_as_obstacle() {
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+        let mut cal = Calendar::new("Morning");
+        for (title, from, to) in [
+            ("Early", "2025-11-03 08:30:00", "2025-11-03 10:00:00"),
+            ("Call", "2025-11-03 09:15:00", "2025-11-03 09:45:00"),
+        ] {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start_datetime(at(from))
+                    .end_datetime(at(to))
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let (start, end) = (at("2025-11-03 09:00:00"), at("2025-11-03 18:00:00"));
+        assert_eq!(cal.resolve_conflicts(start, end, Duration::zero()).unwrap(), 1);
+        // Early started before the window, so it stays and Call moves after it
+        assert_eq!(cal.events[0].start_time, at("2025-11-03 08:30:00"));
+        assert_eq!(cal.events[1].start_time, at("2025-11-03 10:00:00"));
+        assert!(crate::gap_validation::find_overlaps(&cal, start, end).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_conflicts_introduced_by_reschedule() {
+        let mut moving = titled("1:1", "2025-11-03 09:00:00");
+        moving.uid = Some("one-on-one".to_string());
+        let before: Calendar = vec![
+            moving,
+            titled("Standup", "2025-11-03 09:30:00"),
+            titled("Design", "2025-11-03 14:00:00"),
+            titled("Interview", "2025-11-03 14:30:00"),
+            titled("Focus", "2025-11-03 16:00:00"),
+        ]
+        .into_iter()
+        .collect();
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+        let (start, end) = (at("2025-11-03 00:00:00"), at("2025-11-04 00:00:00"));
+
+        let mut after = before.clone();
+        after.events[0]
+            .reschedule(at("2025-11-03 14:15:00"), at("2025-11-03 15:15:00"))
+            .unwrap();
+
+        assert_eq!(
+            after.conflicts_introduced_by(&before, "one-on-one", start, end).unwrap(),
+            vec!["Design", "Interview"]
+        );
+        // Nothing changed, nothing introduced; the Standup clash predates it
+        assert!(before
+            .conflicts_introduced_by(&before, "one-on-one", start, end)
+            .unwrap()
+            .is_empty());
+        assert!(after.conflicts_introduced_by(&before, "missing", start, end).is_err());
+    }
+
+    #[test]
+    fn test_events_between_skips_series_that_ended_before_window() {
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+
+        let mut cal = Calendar::new("History");
+        for title in ["Old standup", "Old sync", "Old review"] {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start("2024-01-01 09:00:00", "UTC")
+                    .duration_minutes(30)
+                    .recurrence(Recurrence::daily().until(at("2024-06-30 09:00:00")))
+                    .build()
+                    .unwrap(),
+            );
+        }
+        cal.add_event(titled("Old one-off", "2024-03-01 09:00:00"));
+        cal.add_event(
+            Event::builder()
+                .title("Future series")
+                .start("2026-01-01 09:00:00", "UTC")
+                .duration_minutes(30)
+                .recurrence(Recurrence::daily())
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Standup")
+                .start("2025-01-01 09:00:00", "UTC")
+                .duration_minutes(15)
+                .recurrence(Recurrence::daily())
+                .build()
+                .unwrap(),
+        );
+
+        let (start, end) = (at("2025-11-01 00:00:00"), at("2025-12-01 00:00:00"));
+
+        let before = EXPANSIONS.with(|count| count.get());
+        let per_event = cal.expand_serial(start, end, 1000).unwrap();
+        assert_eq!(EXPANSIONS.with(|count| count.get()) - before, 1);
+        assert!(per_event[..5].iter().all(|occurrences| occurrences.is_empty()));
+        assert_eq!(per_event[5].len(), 30);
+
+        let occurrences = cal.events_between(start, end).unwrap();
+        assert_eq!(occurrences.len(), 30);
+        assert!(occurrences.iter().all(|o| o.title() == "Standup"));
+
+        // An UNTIL series still reaches a window that overlaps its last occurrence
+        let last = cal
+            .events_between(at("2024-06-30 09:15:00"), at("2024-07-01 00:00:00"))
+            .unwrap();
+        assert_eq!(last.len(), 3);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_expansion_matches_serial() {
+        use crate::Recurrence;
+
+        let mut cal = Calendar::new("Busy");
+        for i in 0..200 {
+            let mut builder = Event::builder()
+                .title(format!("Event {}", i))
+                .start(&format!("2025-11-{:02} {:02}:00:00", 1 + i % 28, 8 + i % 9), "UTC")
+                .duration_minutes(45);
+            if i % 3 == 0 {
+                builder = builder.recurrence(Recurrence::daily().count(10));
+            }
+            cal.add_event(builder.build().unwrap());
+        }
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-11-01 00:00:00", tz).unwrap();
+        let end = crate::timezone::parse_datetime_with_tz("2025-12-15 00:00:00", tz).unwrap();
+
+        assert_eq!(
+            cal.expand_parallel(start, end, 100_000).unwrap(),
+            cal.expand_serial(start, end, 100_000).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_calendar_from_iterator() {
+        let cal: Calendar = vec![
+            titled("One", "2025-11-01 09:00:00"),
+            titled("Two", "2025-11-01 10:00:00"),
+            titled("Three", "2025-11-01 11:00:00"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(cal.name, "Calendar");
+        assert_eq!(cal.event_count(), 3);
+        assert_eq!(cal.events[2].title, "Three");
+    }
+
+    #[test]
+    fn test_calendar_index_accessors() {
+        let mut cal = Calendar::new("Accessors");
+        assert!(cal.is_empty());
+        assert!(cal.event(0).is_none());
+
+        cal.add_event(titled("First", "2025-11-01 09:00:00"));
+        cal.add_event(titled("Second", "2025-11-01 11:00:00"));
+        assert_eq!(cal.len(), 2);
+        assert!(!cal.is_empty());
+
+        assert_eq!(cal.event(1).map(|e| e.title.as_str()), Some("Second"));
+        assert!(cal.event(2).is_none());
+
+        cal.event_mut(0).unwrap().title = "Renamed".to_string();
+        assert_eq!(cal.event(0).unwrap().title, "Renamed");
+        assert!(cal.event_mut(5).is_none());
+    }
+
+    #[test]
+    fn test_events_today_and_this_week_windows() {
+        let tz = crate::timezone::parse_timezone("America/New_York").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+        let event = |title: &str, start: &str| {
+            Event::builder()
+                .title(title)
+                .start(start, "America/New_York")
+                .duration_hours(1)
+                .build()
+                .unwrap()
+        };
+
+        let cal: Calendar = vec![
+            event("Last week", "2025-11-02 10:00:00"),
+            event("Yesterday", "2025-11-04 23:00:00"),
+            event("Today", "2025-11-05 09:00:00"),
+            event("Sunday", "2025-11-09 20:00:00"),
+            event("Next week", "2025-11-10 08:00:00"),
+        ]
+        .into_iter()
+        .collect();
+
+        // Wednesday 2025-11-05
+        let now = at("2025-11-05 12:00:00");
+        let titles = |occurrences: Vec<EventOccurrence<'_>>| {
+            occurrences.iter().map(|o| o.title().to_string()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(titles(cal.events_on_date(now).unwrap()), vec!["Today"]);
+        assert_eq!(
+            titles(cal.events_in_week_of(now, Weekday::Mon).unwrap()),
+            vec!["Yesterday", "Today", "Sunday"]
+        );
+        assert_eq!(
+            titles(cal.events_in_week_of(now, Weekday::Sun).unwrap()),
+            vec!["Last week", "Yesterday", "Today"]
+        );
+        // The week starts today when today is the week start, running to Tuesday
+        assert_eq!(
+            titles(cal.events_in_week_of(now, Weekday::Wed).unwrap()),
+            vec!["Today", "Sunday", "Next week"]
+        );
+
+        // The system-clock variants run against the real clock
+        assert!(cal.events_today(tz).is_ok());
+        assert!(cal.events_this_week(tz, Weekday::Mon).is_ok());
+    }
 
-#[cfg(test)]
-mod tests {
-    #![allow(clippy::unwrap_used)]
-    use super::*;
-    use crate::Event;
+    #[test]
+    fn test_events_today_with_fixed_clock_is_deterministic() {
+        use crate::clock::FixedClock;
+
+        let tz = crate::timezone::parse_timezone("America/New_York").unwrap();
+        let cal: Calendar = vec![
+            titled("Late last night", "2025-11-04 23:30:00"),
+            titled("Standup", "2025-11-05 09:00:00"),
+            titled("Tomorrow", "2025-11-06 09:00:00"),
+        ]
+        .into_iter()
+        .collect();
+
+        // 03:00 UTC on Nov 6 is still 22:00 on Nov 5 in New York
+        let clock = FixedClock::new(
+            crate::timezone::parse_datetime_with_tz("2025-11-06 03:00:00", chrono_tz::UTC).unwrap(),
+        );
+
+        let today = cal.events_today_with_clock(tz, &clock).unwrap();
+        let titles: Vec<_> = today.iter().map(|o| o.title()).collect();
+        assert_eq!(titles, vec!["Standup"]);
+
+        let again = cal.events_today_with_clock(tz, &clock).unwrap();
+        assert_eq!(again.len(), today.len());
+
+        let week = cal.events_this_week_with_clock(tz, Weekday::Mon, &clock).unwrap();
+        assert_eq!(week.len(), 3);
+    }
 
     #[test]
-    fn test_calendar_creation() {
-        let cal = Calendar::new("Test Calendar").description("A test calendar");
+    fn test_events_this_week_sunday_vs_monday_start() {
+        use crate::clock::FixedClock;
+
+        let tz = crate::timezone::parse_timezone("America/New_York").unwrap();
+        let cal: Calendar = vec![
+            titled("Sat before", "2025-11-01 12:00:00"),
+            titled("Sunday", "2025-11-02 12:00:00"),
+            titled("Monday", "2025-11-03 12:00:00"),
+            titled("Next Sunday", "2025-11-09 12:00:00"),
+            titled("Next Monday", "2025-11-10 12:00:00"),
+        ]
+        .into_iter()
+        .collect();
+
+        // Wednesday 2025-11-05, midday in New York
+        let clock = FixedClock::new(
+            crate::timezone::parse_datetime_with_tz("2025-11-05 12:00:00", tz).unwrap(),
+        );
+        let titles = |start: Weekday| -> Vec<String> {
+            cal.events_this_week_with_clock(tz, start, &clock)
+                .unwrap()
+                .iter()
+                .map(|o| o.title().to_string())
+                .collect()
+        };
 
-        assert_eq!(cal.name, "Test Calendar");
-        assert_eq!(cal.description, Some("A test calendar".to_string()));
-        assert_eq!(cal.event_count(), 0);
+        // US-style week: Sun Nov 2 – Sat Nov 8
+        assert_eq!(titles(Weekday::Sun), vec!["Sunday", "Monday"]);
+        // ISO week: Mon Nov 3 – Sun Nov 9, one day later on both ends
+        assert_eq!(titles(Weekday::Mon), vec!["Monday", "Next Sunday"]);
+    }
+
+    #[test]
+    fn test_occurrence_uid_embeds_original_start() {
+        let tz = crate::timezone::parse_timezone("America/New_York").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+
+        let mut event = Event::builder()
+            .title("Meeting")
+            .start("2025-11-03 09:00:00", "America/New_York")
+            .duration_hours(1)
+            .recurrence(Recurrence::daily().count(3))
+            .uid("meeting@x")
+            .build()
+            .unwrap();
+        // Move the third occurrence to the afternoon
+        event.overrides.insert(
+            at("2025-11-05 09:00:00"),
+            EventOverride::new(at("2025-11-05 15:00:00"), at("2025-11-05 16:00:00")),
+        );
+        let mut cal: Calendar = vec![event].into_iter().collect();
+        cal.add_event(titled("Untracked", "2025-11-03 12:00:00"));
+
+        let occurrences = cal
+            .events_between(at("2025-11-01 00:00:00"), at("2025-11-10 00:00:00"))
+            .unwrap();
+        let uids: Vec<String> = occurrences.iter().map(|o| o.occurrence_uid()).collect();
+        assert_eq!(
+            uids,
+            vec![
+                "event-1_20251103T120000Z",
+                "meeting@x_20251103T140000Z",
+                "meeting@x_20251104T140000Z",
+                "meeting@x_20251105T140000Z",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_into_iterator() {
+        let cal: Calendar =
+            [titled("One", "2025-11-01 09:00:00"), titled("Two", "2025-11-01 10:00:00")]
+                .into_iter()
+                .collect();
+
+        let mut count = 0;
+        for event in &cal {
+            assert!(!event.title.is_empty());
+            count += 1;
+        }
+        assert_eq!(count, 2);
+
+        let titles: Vec<String> = cal.into_iter().map(|e| e.title).collect();
+        assert_eq!(titles, vec!["One", "Two"]);
     }
 
     #[test]
@@ -670,6 +2907,66 @@ mod tests {
         assert_eq!(ev.exdates.len(), 1);
     }
 
+    #[test]
+    fn test_json_reminders_use_iso8601_durations() {
+        let reminder = crate::Reminder::RelativeToStart(Duration::minutes(-15));
+        let json = serde_json::to_string(&reminder).unwrap();
+        assert_eq!(json, r#"{"RelativeToStart":"-PT15M"}"#);
+        let parsed: crate::Reminder = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, crate::Reminder::RelativeToStart(Duration::minutes(-15)));
+
+        let mut cal = Calendar::new("Reminders");
+        cal.add_event(
+            Event::builder()
+                .title("Exam")
+                .start("2025-11-01 09:00:00", "UTC")
+                .duration_hours(3)
+                .reminder(reminder)
+                .reminder(crate::Reminder::RelativeToEnd(-Duration::minutes(10)))
+                .build()
+                .unwrap(),
+        );
+
+        let json = cal.to_json().unwrap();
+        assert!(json.contains("\"-PT10M\""));
+        let restored = Calendar::from_json(&json).unwrap();
+        assert_eq!(restored.events[0].reminders, cal.events[0].reminders);
+
+        let bad = r#"{"name":"x","events":[{"title":"t","start_time":"2025-11-01T09:00:00+00:00",
+            "end_time":"2025-11-01T10:00:00+00:00","timezone":"UTC",
+            "reminders":[{"RelativeToStart":"-900"}]}]}"#;
+        assert!(Calendar::from_json(bad).is_err());
+    }
+
+    #[test]
+    fn test_json_roundtrip_preserves_event_metadata() {
+        let created = chrono::DateTime::parse_from_rfc3339("2025-10-01T08:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let mut event = Event::builder()
+            .title("Offsite")
+            .start("2025-11-01 09:00:00", "Europe/Berlin")
+            .duration_hours(8)
+            .category("team")
+            .priority(2)
+            .transparency(Transparency::Transparent)
+            .class(Classification::Confidential)
+            .attachment("https://example.com/agenda.pdf")
+            .extra_property("X-TAG", "red")
+            .extra_property("X-TAG", "blue")
+            .build()
+            .unwrap();
+        event.sequence = 3;
+        event.created = Some(created);
+        event.last_modified = Some(created + Duration::days(1));
+        event.language = Some("de".to_string());
+        event.description_html = Some("<b>Bring laptops</b>".to_string());
+
+        let cal: Calendar = vec![event].into_iter().collect();
+        let restored = Calendar::from_json(&cal.to_json().unwrap()).unwrap();
+        assert_eq!(restored.events, cal.events);
+    }
+
     #[test]
     fn test_json_import_rejects_bad_recurrence() {
         // Malformed recurrence frequency should fail import, not silently drop
@@ -901,4 +3198,571 @@ mod tests {
         let occurrences = cal.events_on_date(query_date).unwrap();
         assert_eq!(occurrences.len(), 1);
     }
+
+    #[test]
+    fn test_duration_stats_mean_and_median() {
+        use crate::timezone::{parse_datetime_with_tz, parse_timezone};
+        let mut cal = Calendar::new("Stats");
+        for (title, time, minutes) in
+            [("Quick", "09:00:00", 15), ("Sync", "10:00:00", 30), ("Deep Dive", "13:00:00", 60)]
+        {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start(&format!("2025-11-03 {}", time), "UTC")
+                    .duration_minutes(minutes)
+                    .build()
+                    .unwrap(),
+            );
+        }
+        cal.add_event(
+            Event::builder()
+                .title("Cancelled")
+                .start("2025-11-03 15:00:00", "UTC")
+                .duration_hours(3)
+                .status(crate::EventStatus::Cancelled)
+                .build()
+                .unwrap(),
+        );
+
+        let tz = parse_timezone("UTC").unwrap();
+        let start = parse_datetime_with_tz("2025-11-03 00:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-11-04 00:00:00", tz).unwrap();
+
+        let stats = cal.duration_stats(start, end).unwrap();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.total, Duration::minutes(105));
+        assert_eq!(stats.mean, Duration::minutes(35));
+        assert_eq!(stats.median, Duration::minutes(30));
+        assert_eq!(stats.min, Duration::minutes(15));
+        assert_eq!(stats.max, Duration::minutes(60));
+
+        let empty = Calendar::new("Empty").duration_stats(start, end).unwrap();
+        assert_eq!(empty.count, 0);
+        assert_eq!(empty.mean, Duration::zero());
+    }
+
+    #[test]
+    fn test_duration_stats_uses_override_durations() {
+        use crate::timezone::{parse_datetime_with_tz, parse_timezone};
+        let tz = parse_timezone("UTC").unwrap();
+        let at = |s: &str| parse_datetime_with_tz(s, tz).unwrap();
+
+        let mut event = Event::builder()
+            .title("Workshop")
+            .start("2025-11-03 09:00:00", "UTC")
+            .duration_hours(1)
+            .recurrence(Recurrence::daily().count(3))
+            .build()
+            .unwrap();
+        // The second session runs four hours instead of one
+        event.overrides.insert(
+            at("2025-11-04 09:00:00"),
+            EventOverride::new(at("2025-11-04 09:00:00"), at("2025-11-04 13:00:00")),
+        );
+        let cal: Calendar = vec![event].into_iter().collect();
+
+        let (start, end) = (at("2025-11-03 00:00:00"), at("2025-11-06 00:00:00"));
+        let stats = cal.duration_stats(start, end).unwrap();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.total, Duration::hours(6));
+        assert_eq!(stats.max, Duration::hours(4));
+        assert_eq!(stats.total, cal.events[0].total_duration_between(start, end).unwrap());
+    }
+
+    #[test]
+    fn test_events_between_limited_caps_total_and_reports_truncation() {
+        use crate::timezone::{parse_datetime_with_tz, parse_timezone};
+        let mut cal = Calendar::new("Busy");
+        cal.add_event(
+            Event::builder()
+                .title("Daily")
+                .start("2025-01-01 09:00:00", "UTC")
+                .duration_minutes(30)
+                .recurrence(Recurrence::daily().count(10_000))
+                .build()
+                .unwrap(),
+        );
+
+        let tz = parse_timezone("UTC").unwrap();
+        let start = parse_datetime_with_tz("2025-01-01 00:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2060-01-01 00:00:00", tz).unwrap();
+
+        let (page, truncated) = cal.events_between_limited(start, end, 50).unwrap();
+        assert_eq!(page.len(), 50);
+        assert!(truncated);
+        assert_eq!(page[0].occurrence_time, start + Duration::hours(9));
+        assert_eq!(page[49].occurrence_time, start + Duration::days(49) + Duration::hours(9));
+
+        let small_end = parse_datetime_with_tz("2025-01-04 00:00:00", tz).unwrap();
+        let (page, truncated) = cal.events_between_limited(start, small_end, 50).unwrap();
+        assert_eq!(page.len(), 3);
+        assert!(!truncated);
+    }
+
+    #[test]
+    fn test_summary_lines_describe_recurrence_and_one_off_events() {
+        let mut cal = Calendar::new("Summary");
+        cal.add_event(
+            Event::builder()
+                .title("Standup")
+                .start("2025-11-03 09:00:00", "UTC")
+                .duration_minutes(15)
+                .recurrence(Recurrence::daily().count(20))
+                .skip_weekends(true)
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Offsite")
+                .start("2025-11-14 13:30:00", "UTC")
+                .duration_hours(3)
+                .build()
+                .unwrap(),
+        );
+
+        let lines = cal.summary_lines();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("Standup — 09:00"));
+        assert!(lines[0].contains("Daily"));
+        assert!(lines[0].contains("20 times"));
+        assert!(lines[0].contains("skipping weekends"));
+        assert_eq!(lines[1], "Offsite — 13:30 — Once on 2025-11-14");
+    }
+
+    #[test]
+    fn test_first_occurrences_between_returns_earliest_across_events() {
+        use crate::timezone::{parse_datetime_with_tz, parse_timezone};
+        let mut cal = Calendar::new("Month");
+        for (title, time) in
+            [("Standup", "09:00:00"), ("Lunch", "12:00:00"), ("Review", "08:00:00")]
+        {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start(&format!("2025-11-01 {}", time), "UTC")
+                    .duration_minutes(30)
+                    .recurrence(Recurrence::daily())
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let tz = parse_timezone("UTC").unwrap();
+        let start = parse_datetime_with_tz("2025-11-01 00:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-12-01 00:00:00", tz).unwrap();
+
+        let first = cal.first_occurrences_between(start, end, 7).unwrap();
+        let all = cal.events_between(start, end).unwrap();
+        assert_eq!(all.len(), 90);
+        assert_eq!(first.len(), 7);
+
+        let expected: Vec<_> = all.iter().take(7).map(|o| o.occurrence_time).collect();
+        let actual: Vec<_> = first.iter().map(|o| o.occurrence_time).collect();
+        assert_eq!(actual, expected);
+        assert_eq!(first[0].title(), "Review");
+        assert_eq!(first[3].title(), "Review");
+        assert_eq!(first[6].title(), "Review");
+
+        assert!(cal.first_occurrences_between(start, end, 0).unwrap().is_empty());
+        assert!(cal.first_occurrences_between(end, start, 5).is_err());
+    }
+
+    #[test]
+    fn test_longest_free_day_streak_detects_trailing_run() {
+        use crate::timezone::parse_timezone;
+        let mut cal = Calendar::new("Deep Work");
+        for day in 3..=5 {
+            cal.add_event(
+                Event::builder()
+                    .title("Planning")
+                    .start(&format!("2025-11-{:02} 10:00:00", day), "UTC")
+                    .duration_hours(2)
+                    .build()
+                    .unwrap(),
+            );
+        }
+        cal.add_event(
+            Event::builder()
+                .title("Dropped")
+                .start("2025-11-10 10:00:00", "UTC")
+                .duration_hours(1)
+                .status(crate::EventStatus::Cancelled)
+                .build()
+                .unwrap(),
+        );
+
+        let tz = parse_timezone("UTC").unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 11, 3).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 11, 16).unwrap();
+
+        let (first, last, days) = cal.longest_free_day_streak(start, end, tz).unwrap();
+        assert_eq!(first, NaiveDate::from_ymd_opt(2025, 11, 6).unwrap());
+        assert_eq!(last, end);
+        assert_eq!(days, 11);
+
+        let busy_end = NaiveDate::from_ymd_opt(2025, 11, 5).unwrap();
+        assert_eq!(cal.longest_free_day_streak(start, busy_end, tz).unwrap(), (start, start, 0));
+        assert!(cal.longest_free_day_streak(end, start, tz).is_err());
+    }
+
+    #[test]
+    fn test_padded_busy_intervals_merge_when_padding_consumes_gap() {
+        let mut cal: Calendar = vec![
+            titled("Design", "2025-11-03 09:00:00"),
+            // 20 minutes after Design ends
+            titled("Review", "2025-11-03 10:20:00"),
+            titled("Demo", "2025-11-03 15:00:00"),
+        ]
+        .into_iter()
+        .collect();
+        let mut dropped = titled("Dropped", "2025-11-03 12:00:00");
+        dropped.cancel();
+        cal.add_event(dropped);
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+        let (start, end) = (at("2025-11-03 08:00:00"), at("2025-11-03 18:00:00"));
+
+        assert_eq!(
+            cal.padded_busy_intervals(start, end, Duration::minutes(15)).unwrap(),
+            vec![
+                (at("2025-11-03 08:45:00"), at("2025-11-03 11:35:00")),
+                (at("2025-11-03 14:45:00"), at("2025-11-03 16:15:00")),
+            ]
+        );
+
+        // Smaller padding leaves the 20-minute gap partly free
+        assert_eq!(cal.padded_busy_intervals(start, end, Duration::minutes(5)).unwrap().len(), 3);
+
+        // Padding reaching into the window is clipped to it
+        let late = cal.padded_busy_intervals(at("2025-11-03 16:10:00"), end, Duration::minutes(15));
+        assert_eq!(late.unwrap(), vec![(at("2025-11-03 16:10:00"), at("2025-11-03 16:15:00"))]);
+
+        assert!(cal.padded_busy_intervals(start, end, Duration::minutes(-1)).is_err());
+    }
+
+    #[test]
+    fn test_classify_by_time_buckets_around_now() {
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let now = crate::timezone::parse_datetime_with_tz("2025-11-04 12:00:00", tz).unwrap();
+
+        let mut cal: Calendar = vec![
+            titled("Breakfast", "2025-11-04 08:00:00"),
+            titled("Lunch", "2025-11-04 11:30:00"),
+            titled("Review", "2025-11-04 15:00:00"),
+            // Ends exactly at now
+            titled("Prep", "2025-11-04 11:00:00"),
+        ]
+        .into_iter()
+        .collect();
+        for (title, start, count) in
+            [("Old series", "2025-10-01 09:00:00", 3), ("Daily", "2025-11-01 09:00:00", 30)]
+        {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start(start, "UTC")
+                    .duration_hours(1)
+                    .recurrence(Recurrence::daily().count(count))
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let titles =
+            |events: Vec<&Event>| events.iter().map(|e| e.title.clone()).collect::<Vec<_>>();
+        let (past, current, future) = cal.classify_by_time(now);
+        assert_eq!(titles(past), vec!["Breakfast", "Prep", "Old series"]);
+        assert_eq!(titles(current), vec!["Lunch"]);
+        // The daily series has upcoming occurrences but none in progress
+        assert_eq!(titles(future), vec!["Review", "Daily"]);
+    }
+
+    #[test]
+    fn test_classify_by_time_treats_fully_filtered_series_as_past() {
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let now = crate::timezone::parse_datetime_with_tz("2025-11-04 12:00:00", tz).unwrap();
+        // A Saturday series that skips weekends never occurs
+        let never = Event::builder()
+            .title("Never")
+            .start("2025-11-01 09:00:00", "UTC")
+            .duration_hours(1)
+            .recurrence(Recurrence::weekly())
+            .skip_weekends(true)
+            .build()
+            .unwrap();
+
+        let cal: Calendar = vec![never].into_iter().collect();
+        let (past, current, future) = cal.classify_by_time(now);
+        assert_eq!((past.len(), current.len(), future.len()), (1, 0, 0));
+    }
+
+    #[test]
+    fn test_partition_by_time_splits_straddling_series() {
+        use crate::timezone::{parse_datetime_with_tz, parse_timezone};
+        let mut cal = Calendar::new("Archive");
+        cal.add_event(
+            Event::builder()
+                .title("Standup")
+                .start("2025-11-01 09:00:00", "UTC")
+                .duration_minutes(30)
+                .recurrence(Recurrence::daily().count(10))
+                .uid("standup@example.com")
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Kickoff")
+                .start("2025-11-02 14:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Retro")
+                .start("2025-11-08 14:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+
+        let tz = parse_timezone("UTC").unwrap();
+        let now = parse_datetime_with_tz("2025-11-05 09:15:00", tz).unwrap();
+        let (past, future) = cal.partition_by_time(now);
+
+        let window_start = parse_datetime_with_tz("2025-10-01 00:00:00", tz).unwrap();
+        let window_end = parse_datetime_with_tz("2025-12-01 00:00:00", tz).unwrap();
+        let titles_and_days = |cal: &Calendar| -> Vec<(String, u32)> {
+            use chrono::Datelike;
+            cal.events_between(window_start, window_end)
+                .unwrap()
+                .iter()
+                .map(|o| (o.title().to_string(), o.occurrence_time.day()))
+                .collect()
+        };
+
+        // Nov 1-4 standups have ended; the Nov 5 one is still in progress
+        let past_occurrences = titles_and_days(&past);
+        assert_eq!(past_occurrences.iter().filter(|(t, _)| t == "Standup").count(), 4);
+        assert!(past_occurrences.contains(&("Kickoff".to_string(), 2)));
+        assert!(past.get_events().iter().all(|e| e.title != "Retro"));
+
+        let future_occurrences = titles_and_days(&future);
+        let future_standups: Vec<u32> = future_occurrences
+            .iter()
+            .filter(|(t, _)| t == "Standup")
+            .map(|(_, d)| *d)
+            .collect();
+        assert_eq!(future_standups, vec![5, 6, 7, 8, 9, 10]);
+        assert!(future_occurrences.contains(&("Retro".to_string(), 8)));
+        assert!(future.get_events().iter().all(|e| e.title != "Kickoff"));
+
+        let future_series = future.find_events_by_title("Standup")[0];
+        assert_eq!(future_series.recurrence.as_ref().unwrap().get_count(), Some(6));
+        assert_eq!(future_series.uid.as_deref(), Some("standup@example.com_20251105T090000Z"));
+        assert_eq!(
+            past.find_events_by_title("Standup")[0].uid.as_deref(),
+            Some("standup@example.com")
+        );
+    }
+
+    #[test]
+    fn test_partition_by_time_splits_on_live_occurrences() {
+        use crate::event::EventOverride;
+        use crate::timezone::{parse_datetime_with_tz, parse_timezone};
+        let tz = parse_timezone("UTC").unwrap();
+        let at = |s: &str| parse_datetime_with_tz(s, tz).unwrap();
+
+        // Nov 4 is moved to straddle `now`; Nov 5, which would otherwise
+        // be in progress, is an exception date
+        let mut cal = Calendar::new("Archive");
+        cal.add_event(
+            Event::builder()
+                .title("Standup")
+                .start("2025-11-01 09:00:00", "UTC")
+                .duration_minutes(30)
+                .recurrence(Recurrence::daily().count(10))
+                .uid("standup@example.com")
+                .exception_date(at("2025-11-05 09:00:00"))
+                .occurrence_override(
+                    at("2025-11-04 09:00:00"),
+                    EventOverride::new(at("2025-11-05 08:50:00"), at("2025-11-05 09:20:00")),
+                )
+                .build()
+                .unwrap(),
+        );
+
+        let (past, future) = cal.partition_by_time(at("2025-11-05 09:15:00"));
+        let days = |cal: &Calendar| -> Vec<u32> {
+            use chrono::Datelike;
+            cal.events_between(at("2025-10-01 00:00:00"), at("2025-12-01 00:00:00"))
+                .unwrap()
+                .iter()
+                .map(|o| o.occurrence_time.day())
+                .collect()
+        };
+
+        assert_eq!(days(&past), vec![1, 2, 3]);
+        assert!(past.get_events()[0].overrides.is_empty());
+
+        assert_eq!(days(&future), vec![5, 6, 7, 8, 9, 10]);
+        let later = &future.get_events()[0];
+        assert_eq!(later.start_time, at("2025-11-04 09:00:00"));
+        assert_eq!(later.overrides.len(), 1);
+        assert_eq!(later.uid.as_deref(), Some("standup@example.com_20251104T090000Z"));
+
+        // An exception date on the in-progress occurrence moves the split on
+        let mut cal = Calendar::new("Archive");
+        cal.add_event(
+            Event::builder()
+                .title("Standup")
+                .start("2025-11-01 09:00:00", "UTC")
+                .duration_minutes(30)
+                .recurrence(Recurrence::daily().count(10))
+                .uid("standup@example.com")
+                .exception_date(at("2025-11-05 09:00:00"))
+                .build()
+                .unwrap(),
+        );
+        let (past, future) = cal.partition_by_time(at("2025-11-05 09:15:00"));
+        assert_eq!(days(&past), vec![1, 2, 3, 4]);
+        assert_eq!(days(&future), vec![6, 7, 8, 9, 10]);
+        assert_eq!(
+            future.get_events()[0].uid.as_deref(),
+            Some("standup@example.com_20251106T090000Z")
+        );
+    }
+
+    #[test]
+    fn test_time_span_compares_across_timezones() {
+        let mut cal = Calendar::new("Regions");
+        assert_eq!(cal.time_span(), None);
+
+        for (title, time, tz) in [
+            ("Tokyo sync", "2025-11-03 08:00:00", "Asia/Tokyo"),
+            ("NY review", "2025-11-02 20:00:00", "America/New_York"),
+            ("NY wrap-up", "2025-11-02 21:00:00", "America/New_York"),
+        ] {
+            cal.add_event(
+                Event::builder().title(title).start(time, tz).duration_hours(1).build().unwrap(),
+            );
+        }
+
+        // 08:00 Tokyo is 23:00 UTC on Nov 2, before 20:00 New York (01:00 UTC Nov 3)
+        let (earliest, latest) = cal.time_span().unwrap();
+        assert_eq!(earliest, cal.events[0].start_time);
+        assert_eq!(earliest.timezone(), chrono_tz::Asia::Tokyo);
+        assert_eq!(latest, cal.events[2].end_time);
+        assert_eq!(latest.timezone(), chrono_tz::America::New_York);
+    }
+
+    #[test]
+    fn test_trim_to_budget_shortens_recurring_series() {
+        use crate::timezone::{parse_datetime_with_tz, parse_timezone};
+        let mut cal = Calendar::new("Feed");
+        cal.add_event(
+            Event::builder()
+                .title("Standup")
+                .start("2025-11-03 09:00:00", "UTC")
+                .duration_minutes(15)
+                .recurrence(Recurrence::daily().count(30))
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Weekly")
+                .start("2025-11-03 14:00:00", "UTC")
+                .duration_hours(1)
+                .recurrence(Recurrence::weekly())
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Offsite")
+                .start("2025-11-20 10:00:00", "UTC")
+                .duration_hours(4)
+                .build()
+                .unwrap(),
+        );
+        // Starts after the budget runs out, so none of it survives
+        cal.add_event(
+            Event::builder()
+                .title("Late daily")
+                .start("2025-11-20 09:00:00", "UTC")
+                .duration_minutes(30)
+                .recurrence(Recurrence::daily())
+                .build()
+                .unwrap(),
+        );
+
+        let tz = parse_timezone("UTC").unwrap();
+        let start = parse_datetime_with_tz("2025-11-01 00:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-12-01 00:00:00", tz).unwrap();
+
+        // 28 standups + 4 weeklies + 1 offsite + 11 late dailies
+        assert_eq!(cal.exported_vevent_count(start, end).unwrap(), 44);
+        assert!(!cal.fits_budget(10, start, end).unwrap());
+
+        let report = cal.trim_to_budget(10, start, end).unwrap();
+        assert_eq!(report.len(), 3);
+        assert!(report[0].starts_with("Standup:"));
+        assert!(report[0].contains("(8 of 28 occurrences in range kept)"));
+        assert!(report[1].contains("(1 of 4 occurrences in range kept)"));
+        assert!(report[2].starts_with("Late daily: removed"));
+        assert!(report[2].contains("(0 of 11 occurrences in range kept)"));
+        assert!(cal.find_events_by_title("Late daily").is_empty());
+
+        // Every remaining series survives an ICS round-trip
+        let reimported = Calendar::from_ics_string(&cal.to_ics_string().unwrap()).unwrap();
+        assert_eq!(reimported.event_count(), 3);
+
+        assert_eq!(cal.exported_vevent_count(start, end).unwrap(), 10);
+        assert!(cal.fits_budget(10, start, end).unwrap());
+        assert_eq!(cal.events[0].recurrence.as_ref().unwrap().get_count(), None);
+        assert!(cal.trim_to_budget(10, start, end).unwrap().is_empty());
+
+        assert!(cal.trim_to_budget(0, start, end).is_err());
+    }
+
+    #[test]
+    fn test_find_occurrences_by_title_expands_matching_series() {
+        use crate::timezone::{parse_datetime_with_tz, parse_timezone};
+        let mut cal = Calendar::new("Team");
+        cal.add_event(
+            Event::builder()
+                .title("Daily Standup")
+                .start("2025-11-01 09:00:00", "UTC")
+                .duration_minutes(15)
+                .recurrence(Recurrence::daily())
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Lunch")
+                .start("2025-11-01 12:00:00", "UTC")
+                .duration_hours(1)
+                .recurrence(Recurrence::daily())
+                .build()
+                .unwrap(),
+        );
+
+        let tz = parse_timezone("UTC").unwrap();
+        let start = parse_datetime_with_tz("2025-11-03 00:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-11-10 00:00:00", tz).unwrap();
+
+        let standups = cal.find_occurrences_by_title("standup", start, end).unwrap();
+        assert_eq!(standups.len(), 7);
+        assert!(standups.iter().all(|o| o.title() == "Daily Standup"));
+        assert!(standups.windows(2).all(|w| w[0].occurrence_time < w[1].occurrence_time));
+
+        assert!(cal.find_occurrences_by_title("retro", start, end).unwrap().is_empty());
+        assert!(cal.find_occurrences_by_title("standup", end, start).is_err());
+    }
 }
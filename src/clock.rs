@@ -0,0 +1,58 @@
+//! Injectable time sources for "now"-based queries
+//!
+//! Queries such as [`Calendar::events_today`](crate::Calendar::events_today)
+//! read the current instant from the system clock. Their `_with_clock`
+//! variants take a [`Clock`] instead, so tests can pin "now" with a
+//! [`FixedClock`].
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current instant
+pub trait Clock {
+    /// The current instant in UTC
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock, backed by [`Utc::now`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that always reports the same instant
+///
+/// # Examples
+///
+/// ```
+/// use eventix::clock::{Clock, FixedClock};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+///
+/// let tz = parse_timezone("UTC").unwrap();
+/// let instant = parse_datetime_with_tz("2025-11-05 12:00:00", tz).unwrap();
+/// let clock = FixedClock::new(instant);
+///
+/// assert_eq!(clock.now(), instant);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    instant: DateTime<Utc>,
+}
+
+impl FixedClock {
+    /// Create a clock fixed at `instant`
+    pub fn new<T: chrono::TimeZone>(instant: DateTime<T>) -> Self {
+        Self {
+            instant: instant.with_timezone(&Utc),
+        }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.instant
+    }
+}
@@ -2,16 +2,19 @@
 
 use crate::error::{EventixError, Result};
 use crate::recurrence::{Recurrence, RecurrenceFilter};
-use crate::timezone::{local_day_window, parse_datetime_with_tz, parse_timezone};
-use chrono::{DateTime, Duration};
+use crate::timezone::{local_day_window, parse_datetime_with_tz, parse_timezone, resolve_local};
+use chrono::{DateTime, Duration, Utc};
 use chrono_tz::Tz;
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+/// Skipped dates in a row after which an open-ended occurrence search gives
+/// up, treating the rest of the series as filtered out
+pub const MAX_CONSECUTIVE_SKIPS: usize = 1000;
+
 /// Status of an event in the booking lifecycle
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
-)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 pub enum EventStatus {
     /// The event is confirmed and occupies time (default)
     #[default]
@@ -22,10 +25,124 @@ pub enum EventStatus {
     Cancelled,
     /// The time slot is blocked (similar to Confirmed)
     Blocked,
+    /// An unrecognized status (e.g. `IN-PROCESS` or a vendor value) kept
+    /// verbatim from import; treated as occupying time
+    Other(String),
+}
+
+/// Time transparency of an event (ICS `TRANSP`)
+///
+/// Transparent events stay on the calendar but do not block availability,
+/// which is distinct from being [`EventStatus::Cancelled`].
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum Transparency {
+    /// The event blocks time on the calendar (default)
+    #[default]
+    Opaque,
+    /// The event is informational and leaves the time free
+    Transparent,
+}
+
+/// Access classification of an event (ICS `CLASS`)
+///
+/// Controls how much of the event is visible to others on a shared calendar.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum Classification {
+    /// Visible to everyone with access to the calendar (default)
+    #[default]
+    Public,
+    /// Only the owner sees the event; others see nothing
+    Private,
+    /// Others see the time as busy but not the details
+    Confidential,
+}
+
+/// Rounding direction for [`Event::snap_to_grid`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapMode {
+    /// Round to the closest grid line; exact halves round up
+    Nearest,
+    /// Round to the grid line at or before the time
+    Down,
+    /// Round to the grid line at or after the time
+    Up,
+}
+
+/// A reminder attached to an event (ICS `VALARM`)
+///
+/// Offsets are signed: negative values fire before the reference point.
+/// With serde, offsets are written as ISO 8601 durations such as `"-PT15M"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Reminder {
+    /// Fires at an offset from the event start (`TRIGGER:-PT15M`)
+    RelativeToStart(#[serde(with = "iso8601_duration")] Duration),
+    /// Fires at an offset from the event end (`TRIGGER;RELATED=END:-PT5M`)
+    RelativeToEnd(#[serde(with = "iso8601_duration")] Duration),
+}
+
+/// Serde adapter storing a [`Duration`] as an ISO 8601 string
+mod iso8601_duration {
+    use crate::ics::{format_iso8601_duration, parse_iso8601_duration};
+    use chrono::Duration;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format_iso8601_duration(*duration))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        parse_iso8601_duration(&value)
+            .ok_or_else(|| D::Error::custom(format!("invalid ISO 8601 duration '{}'", value)))
+    }
+}
+
+/// Replacement for a single occurrence of a recurring event (ICS `RECURRENCE-ID`)
+///
+/// Stored in [`Event::overrides`] keyed by the original start of the
+/// occurrence it replaces. An override with [`EventStatus::Cancelled`]
+/// removes the occurrence instead of moving it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventOverride {
+    /// New start time of the occurrence
+    pub start_time: DateTime<Tz>,
+    /// New end time of the occurrence
+    pub end_time: DateTime<Tz>,
+    /// Replacement title, if it differs from the series
+    pub title: Option<String>,
+    /// Replacement description, if it differs from the series
+    pub description: Option<String>,
+    /// Replacement location, if it differs from the series
+    pub location: Option<String>,
+    /// Status of this occurrence
+    pub status: EventStatus,
+}
+
+impl EventOverride {
+    /// Create an override that moves an occurrence to `[start_time, end_time)`
+    pub fn new(start_time: DateTime<Tz>, end_time: DateTime<Tz>) -> Self {
+        Self {
+            start_time,
+            end_time,
+            title: None,
+            description: None,
+            location: None,
+            status: EventStatus::Confirmed,
+        }
+    }
+
+    /// Whether this override removes the occurrence
+    pub fn is_cancelled(&self) -> bool {
+        self.status == EventStatus::Cancelled
+    }
 }
 
 /// A calendar event with timezone-aware start and end times
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Event {
     /// Event title
     pub title: String,
@@ -33,6 +150,12 @@ pub struct Event {
     /// Optional description
     pub description: Option<String>,
 
+    /// Optional HTML rendering of the description (ICS `DESCRIPTION;ALTREP`)
+    pub description_html: Option<String>,
+
+    /// Language tag of the text fields, e.g. `"fr"` (ICS `LANGUAGE` parameter)
+    pub language: Option<String>,
+
     /// Start time with timezone
     pub start_time: DateTime<Tz>,
 
@@ -51,6 +174,11 @@ pub struct Event {
     /// Optional recurrence filter (skip weekends, holidays, etc.)
     pub recurrence_filter: Option<RecurrenceFilter>,
 
+    /// An imported `RRULE` value eventix cannot expand (e.g. using
+    /// `BYEASTER`), kept verbatim so ICS export can re-emit it. Only the
+    /// first occurrence is scheduled; ignored when `recurrence` is set.
+    pub raw_rrule: Option<String>,
+
     /// Specific dates to exclude from recurrence
     pub exdates: Vec<DateTime<Tz>>,
 
@@ -62,6 +190,45 @@ pub struct Event {
 
     /// Status of the event (Confirmed, Cancelled, etc.)
     pub status: EventStatus,
+
+    /// Revision sequence number (ICS `SEQUENCE`), incremented on each update
+    pub sequence: u32,
+
+    /// When the event was first created (ICS `CREATED`)
+    pub created: Option<DateTime<Utc>>,
+
+    /// When the event was last modified (ICS `LAST-MODIFIED`)
+    pub last_modified: Option<DateTime<Utc>>,
+
+    /// Free-form categories for filtering and triage (ICS `CATEGORIES`)
+    pub categories: Vec<String>,
+
+    /// Attachments as URIs, or raw base64 for inline binaries (ICS `ATTACH`)
+    pub attachments: Vec<String>,
+
+    /// Priority from 0 (undefined) to 9 (lowest), 1 being highest (ICS `PRIORITY`)
+    pub priority: Option<u8>,
+
+    /// Whether the event blocks availability (ICS `TRANSP`)
+    pub transparency: Transparency,
+
+    /// Visibility on shared calendars (ICS `CLASS`)
+    pub class: Classification,
+
+    /// Reminders to fire for this event (ICS `VALARM`)
+    pub reminders: Vec<Reminder>,
+
+    /// Vendor `X-` properties preserved across ICS round-trips, as (name, value)
+    pub extra_properties: Vec<(String, String)>,
+
+    /// Sub-components eventix does not model (e.g. RFC 9073 `PARTICIPANT`),
+    /// kept as raw iCalendar text from `BEGIN` to `END` and re-emitted by
+    /// `Calendar::to_ics_string` (but not included in `Calendar::to_ical`)
+    pub raw_subcomponents: Vec<String>,
+
+    /// Per-occurrence overrides of a recurring event, keyed by the original
+    /// occurrence start (ICS `RECURRENCE-ID`)
+    pub overrides: HashMap<DateTime<Tz>, EventOverride>,
 }
 
 impl Event {
@@ -94,6 +261,11 @@ impl Event {
     /// - Filtered-out dates never consume result slots.
     /// - At most `max_occurrences` accepted results are collected, regardless
     ///   of how dense the underlying recurrence is.
+    ///
+    /// An occurrence is returned when its span `[occurrence, occurrence +
+    /// duration)` intersects the query window and it starts before `end`.
+    /// This window is independent of the recurrence's own `until`, which is
+    /// always inclusive (see [`Recurrence::until`]).
     pub fn occurrences_between(
         &self,
         start: DateTime<Tz>,
@@ -109,34 +281,266 @@ impl Event {
             return Ok(vec![]);
         }
 
+        // Stop as soon as we have enough accepted results — never
+        // allocate beyond what the caller asked for.
+        Ok(self.occurrences_in(start, end).take(max_occurrences).collect())
+    }
+
+    /// Lazily yield the accepted occurrence start times intersecting
+    /// `[start, end]`, in chronological order.
+    ///
+    /// Callers are responsible for validating `start <= end`.
+    ///
+    /// Overridden occurrences are yielded at their new start time (if it
+    /// intersects the window), and cancelled overrides are dropped.
+    pub(crate) fn occurrences_in(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Box<dyn Iterator<Item = DateTime<Tz>> + '_> {
+        self.occurrences_with_skip_limit(start, end, usize::MAX)
+    }
+
+    /// Like [`occurrences_in`](Self::occurrences_in), but ends once
+    /// [`MAX_CONSECUTIVE_SKIPS`] candidates in a row are skipped by the
+    /// recurrence filter or exception dates
+    ///
+    /// Use this for searches with an open-ended `end`: an unbounded series
+    /// whose filter rejects every candidate would otherwise never finish.
+    pub(crate) fn occurrences_in_bounded(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Box<dyn Iterator<Item = DateTime<Tz>> + '_> {
+        self.occurrences_with_skip_limit(start, end, MAX_CONSECUTIVE_SKIPS)
+    }
+
+    fn occurrences_with_skip_limit(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        skip_limit: usize,
+    ) -> Box<dyn Iterator<Item = DateTime<Tz>> + '_> {
         if let Some(ref recurrence) = self.recurrence {
             let duration = self.duration();
 
-            let occurrences: Vec<DateTime<Tz>> = recurrence
+            let series = recurrence
                 .occurrences(self.start_time)
                 // Stop once occurrences are entirely past the query window.
                 // Series is chronological, so once dt >= end nothing later
                 // can intersect either.
-                .take_while(|dt| *dt < end)
+                .take_while(move |dt| *dt < end)
                 // Intersection filter: occurrence's time span overlaps [start, end]
-                .filter(|dt| *dt + duration > start)
-                // Apply recurrence filter (skip weekends / skip dates) per element
-                .filter(|dt| !self.is_occurrence_excluded(dt))
-                // Stop as soon as we have enough accepted results — never
-                // allocate beyond what the caller asked for.
-                .take(max_occurrences)
+                .filter(move |dt| *dt + duration > start)
+                // Apply recurrence filter (skip weekends / skip dates) per
+                // element, giving up after `skip_limit` skips in a row
+                .scan(0usize, move |skipped, dt| {
+                    if !self.is_occurrence_excluded(&dt) {
+                        *skipped = 0;
+                        return Some(Some(dt));
+                    }
+                    *skipped += 1;
+                    (*skipped < skip_limit).then_some(None)
+                })
+                .flatten();
+
+            if self.overrides.is_empty() {
+                return Box::new(series);
+            }
+
+            let mut moved: Vec<DateTime<Tz>> = self
+                .overrides
+                .iter()
+                .filter(|(original, o)| {
+                    !o.is_cancelled()
+                        && !self.is_occurrence_excluded(original)
+                        && o.start_time < end
+                        && o.end_time > start
+                })
+                .map(|(_, o)| o.start_time)
                 .collect();
+            moved.sort();
 
-            Ok(occurrences)
+            Box::new(merge_chronological(
+                series.filter(move |dt| !self.overrides.contains_key(dt)),
+                moved.into_iter(),
+            ))
         } else {
             // Non-recurring event: intersection check
-            let event_end = self.end_time;
-            if self.start_time < end && event_end > start {
-                Ok(vec![self.start_time])
-            } else {
-                Ok(vec![])
-            }
+            let intersects = self.start_time < end && self.end_time > start;
+            Box::new(intersects.then_some(self.start_time).into_iter())
+        }
+    }
+
+    /// Get the start of the `n`th occurrence (0-indexed)
+    ///
+    /// Skipped dates (recurrence filter and exception dates) and cancelled
+    /// overrides are not counted, and moved overrides are counted at their
+    /// new time. Returns `None` when the series has fewer than `n + 1`
+    /// occurrences, or when the search gives up after
+    /// [`MAX_CONSECUTIVE_SKIPS`] skipped dates in a row (e.g. an unbounded
+    /// weekly series on Saturdays that skips weekends). Occurrences are
+    /// walked lazily, so nothing is collected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Event, Recurrence};
+    ///
+    /// let event = Event::builder()
+    ///     .title("Course")
+    ///     .start("2025-11-03 18:00:00", "UTC")
+    ///     .duration_hours(2)
+    ///     .recurrence(Recurrence::weekly().count(10))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let tenth = event.nth_occurrence(9).unwrap().unwrap();
+    /// assert_eq!(tenth.format("%Y-%m-%d").to_string(), "2026-01-05");
+    /// assert!(event.nth_occurrence(10).unwrap().is_none());
+    /// ```
+    pub fn nth_occurrence(&self, n: usize) -> Result<Option<DateTime<Tz>>> {
+        let horizon = DateTime::<Utc>::MAX_UTC.with_timezone(&self.start_time.timezone());
+        Ok(self.occurrences_in_bounded(self.start_time, horizon).nth(n))
+    }
+
+    /// Expand the occurrences intersecting `[start, end]` into standalone events
+    ///
+    /// Each returned event copies this event's metadata with the recurrence,
+    /// exception dates and overrides cleared, and its start and end set to
+    /// the occurrence (with any override's title, description, location and
+    /// status applied). Its UID is the occurrence UID, as returned by
+    /// [`EventOccurrence::occurrence_uid`](crate::calendar::EventOccurrence::occurrence_uid);
+    /// events without a UID produce events without one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Event, Recurrence};
+    ///
+    /// let event = Event::builder()
+    ///     .title("Standup")
+    ///     .start("2025-11-03 09:00:00", "UTC")
+    ///     .duration_minutes(15)
+    ///     .recurrence(Recurrence::daily().count(3))
+    ///     .uid("standup@x")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let end = event.start_time + chrono::Duration::days(7);
+    /// let events = event.materialize(event.start_time, end).unwrap();
+    /// assert_eq!(events.len(), 3);
+    /// assert_eq!(events[1].uid.as_deref(), Some("standup@x_20251104T090000Z"));
+    /// assert!(events[1].recurrence.is_none());
+    /// ```
+    pub fn materialize(&self, start: DateTime<Tz>, end: DateTime<Tz>) -> Result<Vec<Event>> {
+        if start > end {
+            return Err(crate::error::EventixError::ValidationError(
+                "Start time must be before or equal to end time".to_string(),
+            ));
         }
+
+        Ok(self
+            .occurrences_in(start, end)
+            .map(|occurrence| {
+                let mut event = self.clone();
+                event.recurrence = None;
+                event.recurrence_filter = None;
+                event.raw_rrule = None;
+                event.exdates.clear();
+                event.overrides.clear();
+                event.start_time = occurrence;
+                event.end_time = self.occurrence_end(occurrence);
+                event.uid = self.uid.as_ref().map(|uid| self.occurrence_uid_for(uid, occurrence));
+                if let Some(o) = self.override_for(occurrence) {
+                    if let Some(ref title) = o.title {
+                        event.title = title.clone();
+                    }
+                    event.description = o.description.clone().or(event.description);
+                    event.location = o.location.clone().or(event.location);
+                    event.status = o.status.clone();
+                }
+                event
+            })
+            .collect())
+    }
+
+    /// Occurrence UID for the occurrence now starting at `occurrence_start`
+    ///
+    /// Joins `uid` and the occurrence's original (pre-override) start in UTC
+    /// basic format, e.g. `meeting@x_20251103T140000Z`.
+    pub(crate) fn occurrence_uid_for(&self, uid: &str, occurrence_start: DateTime<Tz>) -> String {
+        let original = self
+            .override_for(occurrence_start)
+            .and_then(|info| self.overrides.iter().find(|(_, o)| std::ptr::eq(*o, info)))
+            .map_or(occurrence_start, |(original, _)| *original);
+        Self::instance_uid(uid, original)
+    }
+
+    /// Occurrence UID for the series instance originally scheduled at `original`
+    pub(crate) fn instance_uid(uid: &str, original: DateTime<Tz>) -> String {
+        format!("{}_{}", uid, original.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ"))
+    }
+
+    /// End of the series instance originally scheduled at `original`
+    ///
+    /// Applies the instance's override, if any. Returns `None` when the
+    /// instance is skipped by the recurrence filter, an exception date, or
+    /// a cancelled override.
+    pub(crate) fn instance_end(&self, original: DateTime<Tz>) -> Option<DateTime<Tz>> {
+        if self.is_occurrence_excluded(&original) {
+            return None;
+        }
+        match self.overrides.get(&original) {
+            Some(info) if info.is_cancelled() => None,
+            Some(info) => Some(info.end_time),
+            None => Some(original + self.duration()),
+        }
+    }
+
+    /// Look up the override that moved an occurrence to `occurrence_start`
+    ///
+    /// Returns `None` for non-recurring events, cancelled overrides, and
+    /// occurrences that keep their scheduled time.
+    pub fn override_for(&self, occurrence_start: DateTime<Tz>) -> Option<&EventOverride> {
+        self.recurrence.as_ref()?;
+        self.overrides
+            .values()
+            .find(|o| !o.is_cancelled() && o.start_time == occurrence_start)
+    }
+
+    /// End time of the occurrence starting at `occurrence_start`
+    ///
+    /// Uses the override's end when the occurrence was moved, and the
+    /// event's duration otherwise.
+    pub fn occurrence_end(&self, occurrence_start: DateTime<Tz>) -> DateTime<Tz> {
+        match self.override_for(occurrence_start) {
+            Some(o) => o.end_time,
+            None => occurrence_start + self.duration(),
+        }
+    }
+
+    /// Cheap bounds check: can any occurrence intersect `[start, end]`?
+    ///
+    /// `false` means the series provably lies outside the window (it starts
+    /// at or after `end`, or its `UNTIL` bound ends at or before `start`), so
+    /// expansion can be skipped. Events with overrides are always expanded,
+    /// since an override may move an occurrence outside the series bounds.
+    pub(crate) fn may_intersect(&self, start: DateTime<Tz>, end: DateTime<Tz>) -> bool {
+        if !self.overrides.is_empty() {
+            return true;
+        }
+        if self.start_time >= end {
+            return false;
+        }
+        let series_end = match self.recurrence {
+            Some(ref recurrence) => match recurrence.get_until() {
+                Some(until) => until + self.duration(),
+                None => return true,
+            },
+            None => self.end_time,
+        };
+        series_end > start
     }
 
     /// Check whether a single occurrence should be excluded by recurrence
@@ -156,6 +560,45 @@ impl Event {
         self.exdates.contains(dt)
     }
 
+    /// Exception dates that match none of the first `horizon` occurrences
+    ///
+    /// Exception dates match at full date-time precision, so an `EXDATE` on
+    /// the right day but at the wrong time (or on a day the pattern never
+    /// hits) silently has no effect. This returns such dates, in their
+    /// stored order, so callers can warn about them. For a non-recurring
+    /// event every exception date other than its start is reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Event, Recurrence};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let typo = parse_datetime_with_tz("2025-11-05 10:00:00", tz).unwrap();
+    /// let event = Event::builder()
+    ///     .title("Standup")
+    ///     .start("2025-11-03 09:00:00", "UTC")
+    ///     .duration_minutes(15)
+    ///     .recurrence(Recurrence::daily().count(10))
+    ///     .exception_date(typo)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(event.validate_exdates(100), vec![typo]);
+    /// ```
+    pub fn validate_exdates(&self, horizon: usize) -> Vec<DateTime<Tz>> {
+        let occurrences: std::collections::HashSet<DateTime<Tz>> = match self.recurrence {
+            Some(ref recurrence) => recurrence.occurrences(self.start_time).take(horizon).collect(),
+            None => std::iter::once(self.start_time).collect(),
+        };
+        self.exdates
+            .iter()
+            .filter(|exdate| !occurrences.contains(exdate))
+            .copied()
+            .collect()
+    }
+
     /// Check if this event occurs on a specific date
     pub fn occurs_on(&self, date: DateTime<Tz>) -> Result<bool> {
         let (start_dt, end_dt) = local_day_window(date.date_naive(), self.timezone)?;
@@ -163,20 +606,117 @@ impl Event {
         Ok(!occurrences.is_empty())
     }
 
+    /// Get the start time converted to another timezone
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{timezone, Event};
+    ///
+    /// let event = Event::builder()
+    ///     .title("Call")
+    ///     .start("2025-11-03 09:00:00", "America/New_York")
+    ///     .duration_hours(1)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let tokyo = timezone::parse_timezone("Asia/Tokyo").unwrap();
+    /// assert_eq!(event.start_in(tokyo).format("%H:%M").to_string(), "23:00");
+    /// ```
+    pub fn start_in(&self, tz: Tz) -> DateTime<Tz> {
+        self.start_time.with_timezone(&tz)
+    }
+
+    /// Get the end time converted to another timezone
+    pub fn end_in(&self, tz: Tz) -> DateTime<Tz> {
+        self.end_time.with_timezone(&tz)
+    }
+
     /// Get the duration of this event
     pub fn duration(&self) -> Duration {
         self.end_time.signed_duration_since(self.start_time)
     }
 
+    /// Total duration of the occurrences intersecting `[start, end]`
+    ///
+    /// Each occurrence counts with its full duration (an override's own
+    /// length for moved occurrences). The recurrence filter, exception dates
+    /// and cancelled overrides are honoured, as in
+    /// [`occurrences_between`](Self::occurrences_between).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Event, Recurrence};
+    /// use chrono::Duration;
+    ///
+    /// let event = Event::builder()
+    ///     .title("Weekly sync")
+    ///     .start("2025-11-03 10:00:00", "UTC")
+    ///     .duration_hours(1)
+    ///     .recurrence(Recurrence::weekly())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let month = event.start_time + Duration::weeks(4);
+    /// assert_eq!(event.total_duration_between(event.start_time, month).unwrap(), Duration::hours(4));
+    /// ```
+    pub fn total_duration_between(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> Result<Duration> {
+        if start > end {
+            return Err(crate::error::EventixError::ValidationError(
+                "Start time must be before or equal to end time".to_string(),
+            ));
+        }
+
+        Ok(self
+            .occurrences_in(start, end)
+            .map(|occurrence| self.occurrence_end(occurrence) - occurrence)
+            .fold(Duration::zero(), |total, duration| total + duration))
+    }
+
+    /// Returns true if the event runs past local midnight
+    ///
+    /// Dates are compared in the event's timezone. An event ending exactly at
+    /// midnight stays on its start day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::Event;
+    ///
+    /// let shift = Event::builder()
+    ///     .title("Night shift")
+    ///     .start("2025-11-01 22:00:00", "Europe/Berlin")
+    ///     .duration_hours(8)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(shift.is_overnight());
+    /// ```
+    pub fn is_overnight(&self) -> bool {
+        let start = self.start_time.with_timezone(&self.timezone);
+        let last_instant = (self.end_time - Duration::nanoseconds(1)).with_timezone(&self.timezone);
+        last_instant.date_naive() != start.date_naive()
+    }
+
     /// Check if the event is considered "active" (occupies time)
     ///
-    /// Returns true for Confirmed, Tentative, and Blocked.
+    /// Returns true for Confirmed, Tentative, Blocked, and Other.
     /// Returns false for Cancelled.
     pub fn is_active(&self) -> bool {
-        matches!(
-            self.status,
-            EventStatus::Confirmed | EventStatus::Tentative | EventStatus::Blocked
-        )
+        self.status != EventStatus::Cancelled
+    }
+
+    /// Check if the event blocks time for availability purposes
+    ///
+    /// Returns true only for active events that are [`Transparency::Opaque`].
+    /// Gap, overlap, and density analysis use this to ignore events marked
+    /// as free.
+    pub fn blocks_time(&self) -> bool {
+        self.is_active() && self.transparency == Transparency::Opaque
     }
 
     /// Confirm the event
@@ -199,6 +739,95 @@ impl Event {
         self.status = EventStatus::Blocked;
     }
 
+    /// Return this event with a new title
+    ///
+    /// Like [`with_uid`](Self::with_uid) and [`with_status`](Self::with_status),
+    /// this consumes the event, so it chains in iterator `.map(...)` calls;
+    /// clone first to keep the original.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Event, EventStatus};
+    ///
+    /// let template = Event::builder()
+    ///     .title("Draft")
+    ///     .start("2025-11-01 10:00:00", "UTC")
+    ///     .duration_hours(1)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let final_event = template.clone().with_title("Launch").with_uid("launch-1");
+    /// assert_eq!(final_event.title, "Launch");
+    /// assert_eq!(template.title, "Draft");
+    /// ```
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Return this event with a new UID
+    pub fn with_uid(mut self, uid: impl Into<String>) -> Self {
+        self.uid = Some(uid.into());
+        self
+    }
+
+    /// Return this event with a new status
+    pub fn with_status(mut self, status: EventStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Fill in missing metadata from another copy of the same event
+    ///
+    /// Empty `description`, `description_html`, `language`, `location` and
+    /// `priority` are taken from `other`; attendees, categories and
+    /// attachments are unioned, keeping `self`'s order and skipping
+    /// duplicates. Times, recurrence, UID and status are never changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::Event;
+    ///
+    /// let mut ours = Event::builder()
+    ///     .title("Sync")
+    ///     .start("2025-11-03 10:00:00", "UTC")
+    ///     .duration_hours(1)
+    ///     .attendee("alice@example.com")
+    ///     .build()
+    ///     .unwrap();
+    /// let mut theirs = ours.clone().with_title("Sync (copy)");
+    /// theirs.location = Some("Room 4".to_string());
+    ///
+    /// ours.merge_metadata(&theirs);
+    /// assert_eq!(ours.location.as_deref(), Some("Room 4"));
+    /// assert_eq!(ours.title, "Sync");
+    /// ```
+    pub fn merge_metadata(&mut self, other: &Event) {
+        fn fill<T: Clone>(target: &mut Option<T>, source: &Option<T>) {
+            if target.is_none() {
+                target.clone_from(source);
+            }
+        }
+        fn union(target: &mut Vec<String>, source: &[String]) {
+            for item in source {
+                if !target.contains(item) {
+                    target.push(item.clone());
+                }
+            }
+        }
+
+        fill(&mut self.description, &other.description);
+        fill(&mut self.description_html, &other.description_html);
+        fill(&mut self.language, &other.language);
+        fill(&mut self.location, &other.location);
+        fill(&mut self.priority, &other.priority);
+        union(&mut self.attendees, &other.attendees);
+        union(&mut self.categories, &other.categories);
+        union(&mut self.attachments, &other.attachments);
+    }
+
     /// Reschedule the event to a new time
     ///
     /// This updates the start and end times. If the event was Cancelled,
@@ -221,22 +850,131 @@ impl Event {
         }
         Ok(())
     }
+
+    /// Shift the event by a relative offset, preserving its duration
+    ///
+    /// Negative offsets move the event earlier. Like
+    /// [`reschedule`](Self::reschedule), a Cancelled event is reset to
+    /// Confirmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Duration, Event};
+    ///
+    /// let mut event = Event::builder()
+    ///     .title("Sync")
+    ///     .start("2025-11-01 10:00:00", "UTC")
+    ///     .duration_hours(1)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// event.reschedule_by(Duration::minutes(30)).unwrap();
+    /// assert_eq!(event.start_time.format("%H:%M").to_string(), "10:30");
+    /// ```
+    pub fn reschedule_by(&mut self, offset: Duration) -> Result<()> {
+        let shift = |dt: DateTime<Tz>| {
+            dt.checked_add_signed(offset).ok_or_else(|| {
+                EventixError::ValidationError("Rescheduled time is out of range".to_string())
+            })
+        };
+        let new_start = shift(self.start_time)?;
+        let new_end = shift(self.end_time)?;
+        self.reschedule(new_start, new_end)
+    }
+
+    /// Round the start and end times to a grid of `granularity`
+    ///
+    /// Grid lines are multiples of `granularity` counted from local midnight,
+    /// and `mode` is applied to both ends. If rounding would collapse the
+    /// event, the end is pushed one grid step past the start. `granularity`
+    /// must be positive and at most one day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Duration, Event, SnapMode};
+    ///
+    /// let mut event = Event::builder()
+    ///     .title("Sync")
+    ///     .start("2025-11-01 10:07:00", "UTC")
+    ///     .end("2025-11-01 10:52:00")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// event.snap_to_grid(Duration::minutes(15), SnapMode::Up).unwrap();
+    /// assert_eq!(event.start_time.format("%H:%M").to_string(), "10:15");
+    /// assert_eq!(event.end_time.format("%H:%M").to_string(), "11:00");
+    /// ```
+    pub fn snap_to_grid(&mut self, granularity: Duration, mode: SnapMode) -> Result<()> {
+        if granularity <= Duration::zero() || granularity > Duration::days(1) {
+            return Err(EventixError::ValidationError(
+                "Grid granularity must be greater than zero and at most one day".to_string(),
+            ));
+        }
+
+        let start = snap_datetime(self.start_time, granularity, mode)?;
+        let mut end = snap_datetime(self.end_time, granularity, mode)?;
+        if end <= start {
+            end = snap_datetime(start + granularity, granularity, SnapMode::Down)?;
+        }
+
+        self.start_time = start;
+        self.end_time = end;
+        Ok(())
+    }
+}
+
+/// Round a datetime to a multiple of `granularity` since its local midnight
+fn snap_datetime(dt: DateTime<Tz>, granularity: Duration, mode: SnapMode) -> Result<DateTime<Tz>> {
+    let naive = dt.naive_local();
+    let midnight = naive.date().and_time(chrono::NaiveTime::MIN);
+    let out_of_range = || EventixError::ValidationError("Snapped time is out of range".to_string());
+
+    let step = granularity.num_nanoseconds().ok_or_else(out_of_range)?;
+    let since = (naive - midnight).num_nanoseconds().ok_or_else(out_of_range)?;
+    let down = since - since % step;
+    let snapped = match mode {
+        SnapMode::Down => down,
+        SnapMode::Up if since == down => down,
+        SnapMode::Up => down + step,
+        SnapMode::Nearest if (since - down) * 2 >= step => down + step,
+        SnapMode::Nearest => down,
+    };
+
+    resolve_local(dt.timezone(), midnight + Duration::nanoseconds(snapped)).ok_or_else(out_of_range)
 }
 
 /// Builder for creating events with a fluent API
 pub struct EventBuilder {
     title: Option<String>,
     description: Option<String>,
+    description_html: Option<String>,
+    language: Option<String>,
     start_time: Option<DateTime<Tz>>,
     end_time: Option<DateTime<Tz>>,
     timezone: Option<Tz>,
     attendees: Vec<String>,
     recurrence: Option<Recurrence>,
     recurrence_filter: Option<RecurrenceFilter>,
+    raw_rrule: Option<String>,
     exdates: Vec<DateTime<Tz>>,
     location: Option<String>,
     uid: Option<String>,
     status: EventStatus,
+    sequence: u32,
+    created: Option<DateTime<Utc>>,
+    last_modified: Option<DateTime<Utc>>,
+    categories: Vec<String>,
+    attachments: Vec<String>,
+    priority: Option<u8>,
+    transparency: Transparency,
+    class: Classification,
+    reminders: Vec<Reminder>,
+    extra_properties: Vec<(String, String)>,
+    raw_subcomponents: Vec<String>,
+    overrides: HashMap<DateTime<Tz>, EventOverride>,
+    max_duration: Option<Duration>,
     /// First parsing error encountered during builder chain
     parse_error: Option<EventixError>,
 }
@@ -247,16 +985,32 @@ impl EventBuilder {
         Self {
             title: None,
             description: None,
+            description_html: None,
+            language: None,
             start_time: None,
             end_time: None,
             timezone: None,
             attendees: Vec::new(),
             recurrence: None,
             recurrence_filter: None,
+            raw_rrule: None,
             exdates: Vec::new(),
             location: None,
             uid: None,
             status: EventStatus::default(),
+            sequence: 0,
+            created: None,
+            last_modified: None,
+            categories: Vec::new(),
+            attachments: Vec::new(),
+            priority: None,
+            transparency: Transparency::default(),
+            class: Classification::default(),
+            reminders: Vec::new(),
+            extra_properties: Vec::new(),
+            raw_subcomponents: Vec::new(),
+            overrides: HashMap::new(),
+            max_duration: None,
             parse_error: None,
         }
     }
@@ -273,6 +1027,18 @@ impl EventBuilder {
         self
     }
 
+    /// Set an HTML alternative to the plain-text description
+    pub fn description_html(mut self, html: impl Into<String>) -> Self {
+        self.description_html = Some(html.into());
+        self
+    }
+
+    /// Set the language of the title, description, and location (e.g. `"fr"`)
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
     /// Set the start time using a string and timezone
     ///
     /// # Examples
@@ -309,6 +1075,38 @@ impl EventBuilder {
         self
     }
 
+    /// Set the start time from an RFC 3339 string with an offset or `Z`
+    ///
+    /// The timezone becomes UTC for `Z` or the matching fixed offset
+    /// otherwise; see [`crate::timezone::parse_rfc3339`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::Event;
+    ///
+    /// let event = Event::builder()
+    ///     .title("Meeting")
+    ///     .start_rfc3339("2025-11-01T10:00:00-04:00")
+    ///     .duration_hours(1)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn start_rfc3339(mut self, datetime: &str) -> Self {
+        match crate::timezone::parse_rfc3339(datetime) {
+            Ok(dt) => {
+                self.timezone = Some(dt.timezone());
+                self.start_time = Some(dt);
+            }
+            Err(e) => {
+                if self.parse_error.is_none() {
+                    self.parse_error = Some(e);
+                }
+            }
+        }
+        self
+    }
+
     /// Set the start time directly
     pub fn start_datetime(mut self, datetime: DateTime<Tz>) -> Self {
         self.timezone = Some(datetime.timezone());
@@ -335,92 +1133,293 @@ impl EventBuilder {
         self
     }
 
-    /// Set the end time directly
-    pub fn end_datetime(mut self, datetime: DateTime<Tz>) -> Self {
-        self.end_time = Some(datetime);
+    /// Set the end time directly
+    pub fn end_datetime(mut self, datetime: DateTime<Tz>) -> Self {
+        self.end_time = Some(datetime);
+        self
+    }
+
+    /// Set the duration in hours (calculates end_time from start_time)
+    pub fn duration_hours(mut self, hours: i64) -> Self {
+        if let Some(start) = self.start_time {
+            self.end_time = Some(start + Duration::hours(hours));
+        }
+        self
+    }
+
+    /// Set the duration in minutes (calculates end_time from start_time)
+    pub fn duration_minutes(mut self, minutes: i64) -> Self {
+        if let Some(start) = self.start_time {
+            self.end_time = Some(start + Duration::minutes(minutes));
+        }
+        self
+    }
+
+    /// Set the duration (calculates end_time from start_time)
+    pub fn duration(mut self, duration: Duration) -> Self {
+        if let Some(start) = self.start_time {
+            self.end_time = Some(start + duration);
+        }
+        self
+    }
+
+    /// Add an attendee
+    pub fn attendee(mut self, attendee: impl Into<String>) -> Self {
+        self.attendees.push(attendee.into());
+        self
+    }
+
+    /// Add an attendee, checking that it looks like an email address
+    ///
+    /// Unlike [`attendee`](Self::attendee), a malformed address (missing `@`,
+    /// empty local part, a domain without a dot, or whitespace) is recorded
+    /// as a [`EventixError::ValidationError`] and returned by
+    /// [`build`](Self::build).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::Event;
+    ///
+    /// let result = Event::builder()
+    ///     .title("Review")
+    ///     .start("2025-11-01 10:00:00", "UTC")
+    ///     .duration_hours(1)
+    ///     .attendee_email("not-an-email")
+    ///     .build();
+    /// assert!(result.is_err());
+    /// ```
+    pub fn attendee_email(mut self, email: impl Into<String>) -> Self {
+        let email = email.into();
+        if is_valid_email(&email) {
+            self.attendees.push(email);
+        } else if self.parse_error.is_none() {
+            self.parse_error = Some(EventixError::ValidationError(format!(
+                "Invalid attendee email address: '{}'",
+                email
+            )));
+        }
+        self
+    }
+
+    /// Set multiple attendees
+    pub fn attendees(mut self, attendees: Vec<String>) -> Self {
+        self.attendees = attendees;
+        self
+    }
+
+    /// Set the recurrence pattern
+    pub fn recurrence(mut self, recurrence: Recurrence) -> Self {
+        self.recurrence = Some(recurrence);
+        self
+    }
+
+    /// Keep an `RRULE` value verbatim for export without expanding it
+    ///
+    /// See [`Event::raw_rrule`]; ICS import uses this for rules with parts
+    /// eventix does not support.
+    pub fn raw_rrule(mut self, rrule: impl Into<String>) -> Self {
+        self.raw_rrule = Some(rrule.into());
+        self
+    }
+
+    /// Enable skipping weekends for recurring events
+    pub fn skip_weekends(mut self, skip: bool) -> Self {
+        let filter = self.recurrence_filter.unwrap_or_default();
+        self.recurrence_filter = Some(filter.skip_weekends(skip));
+        self
+    }
+
+    /// Add exception dates (dates to skip)
+    pub fn exception_dates(mut self, dates: Vec<DateTime<Tz>>) -> Self {
+        self.exdates = dates;
+        self
+    }
+
+    /// Add a single exception date
+    pub fn exception_date(mut self, date: DateTime<Tz>) -> Self {
+        self.exdates.push(date);
+        self
+    }
+
+    /// Add every occurrence of `pattern` in `[from, to)` as an exception date
+    ///
+    /// The pattern is expanded starting at `from`, so `from` should carry
+    /// the series' time of day for the exception dates to line up with its
+    /// occurrences. An empty window is reported by [`build`](Self::build).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Event, Recurrence};
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let tz = parse_timezone("UTC").unwrap();
+    /// let from = parse_datetime_with_tz("2025-11-03 09:00:00", tz).unwrap();
+    /// let to = parse_datetime_with_tz("2025-11-17 00:00:00", tz).unwrap();
+    ///
+    /// let event = Event::builder()
+    ///     .title("Standup")
+    ///     .start("2025-11-03 09:00:00", "UTC")
+    ///     .duration_minutes(15)
+    ///     .recurrence(Recurrence::daily().count(14))
+    ///     .exclude_recurrence(Recurrence::weekly().weekdays(vec![rrule::Weekday::Fri]), from, to)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(event.exdates.len(), 2);
+    /// ```
+    pub fn exclude_recurrence(
+        mut self,
+        pattern: Recurrence,
+        from: DateTime<Tz>,
+        to: DateTime<Tz>,
+    ) -> Self {
+        if from >= to {
+            if self.parse_error.is_none() {
+                self.parse_error = Some(EventixError::ValidationError(
+                    "Exclusion window start must be before its end".to_string(),
+                ));
+            }
+            return self;
+        }
+        self.exdates.extend(pattern.occurrences(from).take_while(|dt| *dt < to));
+        self
+    }
+
+    /// Set the location
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Set a unique identifier
+    pub fn uid(mut self, uid: impl Into<String>) -> Self {
+        self.uid = Some(uid.into());
         self
     }
 
-    /// Set the duration in hours (calculates end_time from start_time)
-    pub fn duration_hours(mut self, hours: i64) -> Self {
-        if let Some(start) = self.start_time {
-            self.end_time = Some(start + Duration::hours(hours));
-        }
+    /// Set the event status
+    pub fn status(mut self, status: EventStatus) -> Self {
+        self.status = status;
         self
     }
 
-    /// Set the duration in minutes (calculates end_time from start_time)
-    pub fn duration_minutes(mut self, minutes: i64) -> Self {
-        if let Some(start) = self.start_time {
-            self.end_time = Some(start + Duration::minutes(minutes));
-        }
+    /// Set the revision sequence number
+    pub fn sequence(mut self, sequence: u32) -> Self {
+        self.sequence = sequence;
         self
     }
 
-    /// Set the duration (calculates end_time from start_time)
-    pub fn duration(mut self, duration: Duration) -> Self {
-        if let Some(start) = self.start_time {
-            self.end_time = Some(start + duration);
-        }
+    /// Set the creation timestamp
+    pub fn created(mut self, created: DateTime<Utc>) -> Self {
+        self.created = Some(created);
         self
     }
 
-    /// Add an attendee
-    pub fn attendee(mut self, attendee: impl Into<String>) -> Self {
-        self.attendees.push(attendee.into());
+    /// Set the last-modified timestamp
+    pub fn last_modified(mut self, last_modified: DateTime<Utc>) -> Self {
+        self.last_modified = Some(last_modified);
         self
     }
 
-    /// Set multiple attendees
-    pub fn attendees(mut self, attendees: Vec<String>) -> Self {
-        self.attendees = attendees;
+    /// Add a category
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.categories.push(category.into());
         self
     }
 
-    /// Set the recurrence pattern
-    pub fn recurrence(mut self, recurrence: Recurrence) -> Self {
-        self.recurrence = Some(recurrence);
+    /// Set multiple categories
+    pub fn categories(mut self, categories: Vec<String>) -> Self {
+        self.categories = categories;
         self
     }
 
-    /// Enable skipping weekends for recurring events
-    pub fn skip_weekends(mut self, skip: bool) -> Self {
-        let filter = self.recurrence_filter.unwrap_or_default();
-        self.recurrence_filter = Some(filter.skip_weekends(skip));
+    /// Attach a document by URI, such as an agenda link
+    pub fn attachment(mut self, uri: impl Into<String>) -> Self {
+        self.attachments.push(uri.into());
         self
     }
 
-    /// Add exception dates (dates to skip)
-    pub fn exception_dates(mut self, dates: Vec<DateTime<Tz>>) -> Self {
-        self.exdates = dates;
+    /// Set the priority (0-9 per RFC 5545, 1 being highest)
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.priority = Some(priority);
         self
     }
 
-    /// Add a single exception date
-    pub fn exception_date(mut self, date: DateTime<Tz>) -> Self {
-        self.exdates.push(date);
+    /// Reject events longer than `max` when [`build`](Self::build) is called
+    ///
+    /// The cap is a validation rule only; it is not stored on the event.
+    pub fn max_duration(mut self, max: Duration) -> Self {
+        self.max_duration = Some(max);
         self
     }
 
-    /// Set the location
-    pub fn location(mut self, location: impl Into<String>) -> Self {
-        self.location = Some(location.into());
+    /// Set the time transparency
+    pub fn transparency(mut self, transparency: Transparency) -> Self {
+        self.transparency = transparency;
         self
     }
 
-    /// Set a unique identifier
-    pub fn uid(mut self, uid: impl Into<String>) -> Self {
-        self.uid = Some(uid.into());
+    /// Set the access classification
+    pub fn class(mut self, class: Classification) -> Self {
+        self.class = class;
         self
     }
 
-    /// Set the event status
-    pub fn status(mut self, status: EventStatus) -> Self {
-        self.status = status;
+    /// Add a reminder
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Duration, Event, Reminder};
+    ///
+    /// let event = Event::builder()
+    ///     .title("Workshop")
+    ///     .start("2025-11-01 10:00:00", "UTC")
+    ///     .duration_hours(2)
+    ///     .reminder(Reminder::RelativeToStart(-Duration::minutes(15)))
+    ///     .reminder(Reminder::RelativeToEnd(-Duration::minutes(5)))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(event.reminders.len(), 2);
+    /// ```
+    pub fn reminder(mut self, reminder: Reminder) -> Self {
+        self.reminders.push(reminder);
+        self
+    }
+
+    /// Add a vendor `X-` property to be written on ICS export
+    pub fn extra_property(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra_properties.push((name.into(), value.into()));
+        self
+    }
+
+    /// Add a raw iCalendar sub-component to be written inside the `VEVENT`
+    ///
+    /// See [`Event::raw_subcomponents`].
+    pub fn raw_subcomponent(mut self, component: impl Into<String>) -> Self {
+        self.raw_subcomponents.push(component.into());
+        self
+    }
+
+    /// Override the occurrence originally starting at `recurrence_id`
+    pub fn occurrence_override(
+        mut self,
+        recurrence_id: DateTime<Tz>,
+        occurrence: EventOverride,
+    ) -> Self {
+        self.overrides.insert(recurrence_id, occurrence);
         self
     }
 
     /// Build the event
+    ///
+    /// An attached recurrence is validated as well: an interval of 0, a
+    /// `count` of 0, an `until` earlier than the start time, or setting both
+    /// `count` and `until` (which RFC 5545 forbids, so neither takes
+    /// precedence) all fail with [`EventixError::RecurrenceError`].
     pub fn build(self) -> Result<Event> {
         // Surface any parsing error captured during the builder chain
         if let Some(err) = self.parse_error {
@@ -447,29 +1446,118 @@ impl EventBuilder {
             ));
         }
 
+        if let Some(max) = self.max_duration {
+            let duration = end_time - start_time;
+            if duration > max {
+                return Err(EventixError::ValidationError(format!(
+                    "Event duration of {} minutes exceeds the maximum of {} minutes",
+                    duration.num_minutes(),
+                    max.num_minutes()
+                )));
+            }
+        }
+
+        if let Some(priority) = self.priority {
+            if priority > 9 {
+                return Err(EventixError::ValidationError(format!(
+                    "Event priority must be between 0 and 9, got {}",
+                    priority
+                )));
+            }
+        }
+
+        if let Some(ref recurrence) = self.recurrence {
+            if recurrence.get_interval() == 0 {
+                return Err(EventixError::RecurrenceError(
+                    "Recurrence interval must be at least 1".to_string(),
+                ));
+            }
+            if recurrence.get_count() == Some(0) {
+                return Err(EventixError::RecurrenceError(
+                    "Recurrence count must be at least 1".to_string(),
+                ));
+            }
+            if let Some(until) = recurrence.get_until() {
+                if recurrence.get_count().is_some() {
+                    return Err(EventixError::RecurrenceError(
+                        "Recurrence cannot have both count and until".to_string(),
+                    ));
+                }
+                if until < start_time {
+                    return Err(EventixError::RecurrenceError(format!(
+                        "Recurrence until ({}) is before the event start ({})",
+                        until, start_time
+                    )));
+                }
+            }
+        }
+
         Ok(Event {
             title,
             description: self.description,
+            description_html: self.description_html,
+            language: self.language,
             start_time,
             end_time,
             timezone,
             attendees: self.attendees,
             recurrence: self.recurrence,
             recurrence_filter: self.recurrence_filter,
+            raw_rrule: self.raw_rrule,
             exdates: self.exdates,
             location: self.location,
             uid: self.uid,
             status: self.status,
+            sequence: self.sequence,
+            created: self.created,
+            last_modified: self.last_modified,
+            categories: self.categories,
+            attachments: self.attachments,
+            priority: self.priority,
+            transparency: self.transparency,
+            class: self.class,
+            reminders: self.reminders,
+            extra_properties: self.extra_properties,
+            raw_subcomponents: self.raw_subcomponents,
+            overrides: self.overrides,
         })
     }
 }
 
+/// Basic `local@domain.tld` syntax check used by [`EventBuilder::attendee_email`]
+fn is_valid_email(email: &str) -> bool {
+    if email.chars().any(char::is_whitespace) {
+        return false;
+    }
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && domain.split('.').all(|label| !label.is_empty())
+}
+
 impl Default for EventBuilder {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Merge two chronologically sorted streams of start times into one
+fn merge_chronological<'a>(
+    a: impl Iterator<Item = DateTime<Tz>> + 'a,
+    b: impl Iterator<Item = DateTime<Tz>> + 'a,
+) -> impl Iterator<Item = DateTime<Tz>> + 'a {
+    let mut a = a.peekable();
+    let mut b = b.peekable();
+    std::iter::from_fn(move || match (a.peek(), b.peek()) {
+        (Some(x), Some(y)) if y < x => b.next(),
+        (Some(_), _) => a.next(),
+        (None, _) => b.next(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
@@ -534,6 +1622,48 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_event_builder_start_rfc3339() {
+        let utc = Event::builder()
+            .title("Zulu")
+            .start_rfc3339("2025-11-01T14:00:00Z")
+            .duration_hours(1)
+            .build()
+            .unwrap();
+        assert_eq!(utc.timezone, chrono_tz::UTC);
+        assert_eq!(utc.start_time.to_rfc3339(), "2025-11-01T14:00:00+00:00");
+
+        let offset = Event::builder()
+            .title("Offset")
+            .start_rfc3339("2025-11-01T10:00:00-04:00")
+            .duration_hours(1)
+            .build()
+            .unwrap();
+        assert_eq!(offset.timezone.name(), "Etc/GMT+4");
+        assert_eq!(offset.start_time.to_rfc3339(), "2025-11-01T10:00:00-04:00");
+        assert_eq!(offset.start_time, utc.start_time);
+        assert_eq!(offset.end_time.to_rfc3339(), "2025-11-01T11:00:00-04:00");
+
+        let result = Event::builder()
+            .title("Half hour")
+            .start_rfc3339("2025-11-01T10:00:00+05:30")
+            .duration_hours(1)
+            .build();
+        assert!(matches!(result, Err(EventixError::InvalidTimezone(_))));
+        assert!(Event::builder().title("Bad").start_rfc3339("not a date").build().is_err());
+    }
+
+    #[test]
+    fn test_event_builder_rejects_out_of_range_priority() {
+        let result = Event::builder()
+            .title("Urgent")
+            .start("2025-11-01 10:00:00", "UTC")
+            .duration_hours(1)
+            .priority(10)
+            .build();
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_occurrences_between_filter_before_cap() {
         use crate::timezone::parse_timezone;
@@ -695,6 +1825,197 @@ mod tests {
         assert!(!err.contains("required"));
     }
 
+    #[test]
+    fn test_validate_exdates_reports_only_unmatched_dates() {
+        let tz = crate::timezone::parse_timezone("Europe/Berlin").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+        let valid = at("2025-11-05 09:00:00");
+        // Right day, wrong time: the daily 09:00 series never hits 09:30
+        let bogus = at("2025-11-06 09:30:00");
+
+        let event = Event::builder()
+            .title("Standup")
+            .start("2025-11-03 09:00:00", "Europe/Berlin")
+            .duration_minutes(15)
+            .recurrence(Recurrence::daily().count(10))
+            .exception_dates(vec![valid, bogus])
+            .build()
+            .unwrap();
+
+        assert_eq!(event.validate_exdates(100), vec![bogus]);
+        // Outside a short horizon even the valid exdate is unmatched
+        assert_eq!(event.validate_exdates(2), vec![valid, bogus]);
+    }
+
+    #[test]
+    fn test_total_duration_between_honours_exdates() {
+        let mut event = Event::builder()
+            .title("Weekly sync")
+            .start("2025-11-03 10:00:00", "Europe/Berlin")
+            .duration_hours(1)
+            .recurrence(Recurrence::weekly())
+            .build()
+            .unwrap();
+        let start = event.start_time;
+        let end = start + Duration::weeks(4);
+
+        assert_eq!(event.total_duration_between(start, end).unwrap(), Duration::hours(4));
+
+        event.exdates.push(start + Duration::weeks(2));
+        assert_eq!(event.total_duration_between(start, end).unwrap(), Duration::hours(3));
+
+        assert!(event.total_duration_between(end, start).is_err());
+    }
+
+    #[test]
+    fn test_materialize_daily_count_into_concrete_events() {
+        let event = Event::builder()
+            .title("Daily")
+            .start("2025-11-03 09:00:00", "America/New_York")
+            .duration_minutes(30)
+            .location("Room 1")
+            .recurrence(Recurrence::daily().count(5))
+            .uid("daily@x")
+            .build()
+            .unwrap();
+
+        let end = event.start_time + Duration::days(5);
+        let events = event.materialize(event.start_time, end).unwrap();
+
+        assert_eq!(events.len(), 5);
+        for materialized in &events {
+            assert!(materialized.recurrence.is_none());
+            assert!(materialized.overrides.is_empty());
+            assert_eq!(materialized.title, "Daily");
+            assert_eq!(materialized.location.as_deref(), Some("Room 1"));
+            assert_eq!(materialized.duration(), Duration::minutes(30));
+        }
+
+        let starts: std::collections::HashSet<_> = events.iter().map(|e| e.start_time).collect();
+        assert_eq!(starts.len(), 5);
+        let uids: Vec<_> = events.iter().map(|e| e.uid.clone().unwrap()).collect();
+        assert_eq!(uids[0], "daily@x_20251103T140000Z");
+        assert_eq!(uids[4], "daily@x_20251107T140000Z");
+        assert_eq!(uids.iter().collect::<std::collections::HashSet<_>>().len(), 5);
+    }
+
+    #[test]
+    fn test_merge_metadata_fills_gaps_and_unions_attendees() {
+        let mut ours = Event::builder()
+            .title("Planning")
+            .start("2025-11-03 10:00:00", "UTC")
+            .duration_hours(1)
+            .attendee("alice@example.com")
+            .attendee("bob@example.com")
+            .build()
+            .unwrap();
+        let theirs = Event::builder()
+            .title("Planning (imported)")
+            .description("Quarterly planning")
+            .start("2025-11-03 11:00:00", "UTC")
+            .duration_hours(2)
+            .location("Room 4")
+            .attendee("bob@example.com")
+            .attendee("carol@example.com")
+            .build()
+            .unwrap();
+        let (start, end) = (ours.start_time, ours.end_time);
+
+        ours.merge_metadata(&theirs);
+
+        assert_eq!(ours.location.as_deref(), Some("Room 4"));
+        assert_eq!(ours.description.as_deref(), Some("Quarterly planning"));
+        assert_eq!(
+            ours.attendees,
+            vec!["alice@example.com", "bob@example.com", "carol@example.com"]
+        );
+        assert_eq!(ours.title, "Planning");
+        assert_eq!((ours.start_time, ours.end_time), (start, end));
+
+        // Existing values win over the other copy
+        let mut other = theirs.clone();
+        other.location = Some("Room 9".to_string());
+        ours.merge_metadata(&other);
+        assert_eq!(ours.location.as_deref(), Some("Room 4"));
+    }
+
+    #[test]
+    fn test_with_transforms_leave_originals_untouched() {
+        let originals: Vec<Event> = ["09:00:00", "11:00:00", "14:00:00"]
+            .iter()
+            .map(|time| {
+                Event::builder()
+                    .title("Slot")
+                    .start(&format!("2025-11-01 {}", time), "UTC")
+                    .duration_hours(1)
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+
+        let cancelled: Vec<Event> = originals
+            .iter()
+            .cloned()
+            .map(|e| e.with_status(EventStatus::Cancelled))
+            .collect();
+
+        assert!(cancelled.iter().all(|e| e.status == EventStatus::Cancelled));
+        assert!(originals.iter().all(|e| e.status == EventStatus::Confirmed));
+
+        let renamed: Vec<Event> = originals
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                e.clone().with_title(format!("Slot {}", i + 1)).with_uid(format!("slot-{}", i))
+            })
+            .collect();
+        assert_eq!(renamed[2].title, "Slot 3");
+        assert_eq!(renamed[2].uid.as_deref(), Some("slot-2"));
+        assert_eq!(renamed[2].start_time, originals[2].start_time);
+        assert!(originals.iter().all(|e| e.title == "Slot" && e.uid.is_none()));
+    }
+
+    #[test]
+    fn test_exclude_recurrence_drops_fridays_from_daily_series() {
+        let tz = crate::timezone::parse_timezone("Europe/London").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+
+        let event = Event::builder()
+            .title("Standup")
+            .start("2025-11-03 09:00:00", "Europe/London")
+            .duration_minutes(15)
+            .recurrence(Recurrence::daily().count(14))
+            .exclude_recurrence(
+                Recurrence::weekly().weekdays(vec![rrule::Weekday::Fri]),
+                at("2025-11-03 09:00:00"),
+                at("2025-11-17 00:00:00"),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(event.exdates, vec![at("2025-11-07 09:00:00"), at("2025-11-14 09:00:00")]);
+
+        let occurrences = event
+            .occurrences_between(at("2025-11-01 00:00:00"), at("2025-11-30 00:00:00"), 100)
+            .unwrap();
+        assert_eq!(occurrences.len(), 12);
+        assert!(occurrences
+            .iter()
+            .all(|dt| chrono::Datelike::weekday(dt) != chrono::Weekday::Fri));
+
+        let err = Event::builder()
+            .title("Standup")
+            .start("2025-11-03 09:00:00", "Europe/London")
+            .duration_minutes(15)
+            .exclude_recurrence(
+                Recurrence::daily(),
+                at("2025-11-10 00:00:00"),
+                at("2025-11-03 00:00:00"),
+            )
+            .build();
+        assert!(matches!(err, Err(EventixError::ValidationError(_))));
+    }
+
     #[test]
     fn test_exdate_precision_subdaily() {
         use crate::timezone::parse_timezone;
@@ -772,4 +2093,230 @@ mod tests {
         let result = event.occurrences_between(start, end, 5);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_build_rejects_zero_interval_recurrence() {
+        let result = Event::builder()
+            .title("Standup")
+            .start("2025-11-03 09:00:00", "UTC")
+            .duration_minutes(15)
+            .recurrence(crate::Recurrence::daily().interval(0).count(5))
+            .build();
+        assert!(matches!(result, Err(EventixError::RecurrenceError(_))));
+    }
+
+    #[test]
+    fn test_build_rejects_zero_count_recurrence() {
+        let build = |count| {
+            Event::builder()
+                .title("Standup")
+                .start("2025-11-03 09:00:00", "UTC")
+                .duration_minutes(15)
+                .recurrence(crate::Recurrence::daily().count(count))
+                .build()
+        };
+
+        assert!(matches!(build(0), Err(EventixError::RecurrenceError(_))));
+
+        let once = build(1).unwrap();
+        let end = once.start_time + Duration::days(30);
+        assert_eq!(once.occurrences_between(once.start_time, end, 10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_build_rejects_until_before_start() {
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let until = crate::timezone::parse_datetime_with_tz("2025-11-02 09:00:00", tz).unwrap();
+
+        let result = Event::builder()
+            .title("Standup")
+            .start("2025-11-03 09:00:00", "UTC")
+            .duration_minutes(15)
+            .recurrence(crate::Recurrence::daily().until(until))
+            .build();
+        assert!(matches!(result, Err(EventixError::RecurrenceError(_))));
+    }
+
+    #[test]
+    fn test_build_rejects_count_and_until() {
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let until = crate::timezone::parse_datetime_with_tz("2025-11-30 09:00:00", tz).unwrap();
+
+        let result = Event::builder()
+            .title("Standup")
+            .start("2025-11-03 09:00:00", "UTC")
+            .duration_minutes(15)
+            .recurrence(crate::Recurrence::daily().count(5).until(until))
+            .build();
+        assert!(matches!(result, Err(EventixError::RecurrenceError(_))));
+    }
+
+    #[test]
+    fn test_attendee_email_accepts_valid_address() {
+        let event = Event::builder()
+            .title("Review")
+            .start("2025-11-01 10:00:00", "UTC")
+            .duration_hours(1)
+            .attendee_email("alice@example.com")
+            .build()
+            .unwrap();
+        assert_eq!(event.attendees, vec!["alice@example.com".to_string()]);
+    }
+
+    #[test]
+    fn test_attendee_email_rejects_malformed_address() {
+        for bad in ["not-an-email", "@example.com", "bob@localhost", "a b@example.com"] {
+            let result = Event::builder()
+                .title("Review")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .attendee_email(bad)
+                .build();
+            assert!(
+                matches!(result, Err(EventixError::ValidationError(_))),
+                "{} should be rejected",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_snap_to_grid_modes() {
+        let event = Event::builder()
+            .title("Sync")
+            .start("2025-11-01 10:07:00", "UTC")
+            .end("2025-11-01 10:52:00")
+            .build()
+            .unwrap();
+        let hm = |e: &Event| {
+            (e.start_time.format("%H:%M").to_string(), e.end_time.format("%H:%M").to_string())
+        };
+
+        // Each end moves to its own closest grid line: 10:07 -> 10:00, 10:52 -> 10:45
+        let mut nearest = event.clone();
+        nearest.snap_to_grid(Duration::minutes(15), SnapMode::Nearest).unwrap();
+        assert_eq!(hm(&nearest), ("10:00".into(), "10:45".into()));
+
+        let mut down = event.clone();
+        down.snap_to_grid(Duration::minutes(15), SnapMode::Down).unwrap();
+        assert_eq!(hm(&down), ("10:00".into(), "10:45".into()));
+
+        let mut up = event.clone();
+        up.snap_to_grid(Duration::minutes(15), SnapMode::Up).unwrap();
+        assert_eq!(hm(&up), ("10:15".into(), "11:00".into()));
+
+        // A short event that would collapse keeps one grid step
+        let mut short = Event::builder()
+            .title("Ping")
+            .start("2025-11-01 10:02:00", "UTC")
+            .end("2025-11-01 10:05:00")
+            .build()
+            .unwrap();
+        short.snap_to_grid(Duration::minutes(15), SnapMode::Nearest).unwrap();
+        assert_eq!(hm(&short), ("10:00".into(), "10:15".into()));
+
+        let mut invalid = event;
+        assert!(invalid.snap_to_grid(Duration::zero(), SnapMode::Nearest).is_err());
+    }
+
+    #[test]
+    fn test_start_and_end_in_target_zone() {
+        use chrono::Timelike;
+
+        let event = Event::builder()
+            .title("Call")
+            .start("2025-11-03 09:00:00", "America/New_York")
+            .duration_hours(1)
+            .build()
+            .unwrap();
+        let tokyo = parse_timezone("Asia/Tokyo").unwrap();
+
+        let start = event.start_in(tokyo);
+        let end = event.end_in(tokyo);
+        assert_eq!(start.timezone(), tokyo);
+        assert_eq!(start.hour(), 23);
+        assert_eq!(end.hour(), 0);
+        assert_eq!(start, event.start_time);
+        assert_eq!(end, event.end_time);
+    }
+
+    #[test]
+    fn test_nth_occurrence() {
+        let event = Event::builder()
+            .title("Weekly")
+            .start("2025-11-03 09:00:00", "UTC")
+            .duration_hours(1)
+            .recurrence(crate::Recurrence::weekly().count(6))
+            .build()
+            .unwrap();
+
+        assert_eq!(event.nth_occurrence(0).unwrap(), Some(event.start_time));
+        assert_eq!(event.nth_occurrence(4).unwrap(), Some(event.start_time + Duration::weeks(4)));
+        assert_eq!(event.nth_occurrence(6).unwrap(), None);
+
+        // Exception dates are not counted
+        let skipped = Event::builder()
+            .title("Weekly")
+            .start("2025-11-03 09:00:00", "UTC")
+            .duration_hours(1)
+            .recurrence(crate::Recurrence::weekly().count(6))
+            .exception_date(event.start_time + Duration::weeks(1))
+            .build()
+            .unwrap();
+        assert_eq!(skipped.nth_occurrence(1).unwrap(), Some(event.start_time + Duration::weeks(2)));
+
+        let single = Event::builder()
+            .title("Once")
+            .start("2025-11-03 09:00:00", "UTC")
+            .duration_hours(1)
+            .build()
+            .unwrap();
+        assert_eq!(single.nth_occurrence(0).unwrap(), Some(single.start_time));
+        assert_eq!(single.nth_occurrence(1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_nth_occurrence_gives_up_on_fully_filtered_series() {
+        // Every occurrence of an unbounded Saturday series skips weekends
+        let event = Event::builder()
+            .title("Never")
+            .start("2025-11-01 09:00:00", "UTC")
+            .duration_hours(1)
+            .recurrence(crate::Recurrence::weekly())
+            .skip_weekends(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(event.nth_occurrence(0).unwrap(), None);
+    }
+
+    #[test]
+    fn test_max_duration_rejects_longer_events() {
+        let build = |hours| {
+            Event::builder()
+                .title("Workshop")
+                .start("2025-11-01 09:00:00", "UTC")
+                .duration_hours(hours)
+                .max_duration(Duration::hours(4))
+                .build()
+        };
+        assert!(matches!(build(5), Err(EventixError::ValidationError(_))));
+        assert!(build(4).is_ok());
+    }
+
+    #[test]
+    fn test_is_overnight() {
+        let event = |start: &str, end: &str| {
+            Event::builder()
+                .title("Shift")
+                .start(start, "America/New_York")
+                .end(end)
+                .build()
+                .unwrap()
+        };
+        assert!(event("2025-11-01 22:00:00", "2025-11-02 06:00:00").is_overnight());
+        assert!(!event("2025-11-01 09:00:00", "2025-11-01 17:00:00").is_overnight());
+        // Ending exactly at midnight stays on the start day
+        assert!(!event("2025-11-01 22:00:00", "2025-11-02 00:00:00").is_overnight());
+    }
 }
@@ -6,7 +6,9 @@
 
 use crate::calendar::Calendar;
 use crate::error::Result;
-use chrono::{DateTime, Duration};
+use crate::event::EventStatus;
+use crate::timezone::resolve_local;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Weekday};
 use chrono_tz::Tz;
 
 /// Represents a time gap between two events
@@ -22,6 +24,10 @@ pub struct TimeGap {
     pub before_event: Option<String>,
     /// Event after this gap (if any)
     pub after_event: Option<String>,
+    /// Status of the event before this gap, set by [`find_gaps`]
+    pub before_status: Option<EventStatus>,
+    /// Status of the event after this gap, set by [`find_gaps`]
+    pub after_status: Option<EventStatus>,
 }
 
 impl TimeGap {
@@ -39,6 +45,8 @@ impl TimeGap {
             duration,
             before_event,
             after_event,
+            before_status: None,
+            after_status: None,
         }
     }
 
@@ -56,6 +64,57 @@ impl TimeGap {
     pub fn is_at_least(&self, min_duration: Duration) -> bool {
         self.duration >= min_duration
     }
+
+    /// Longest meeting that fits with `buffer` kept free on both sides
+    ///
+    /// Returns the gap duration minus twice the buffer, clamped to zero.
+    pub fn max_bookable(&self, buffer: Duration) -> Duration {
+        (self.duration - buffer * 2).max(Duration::zero())
+    }
+
+    /// Render the gap in the viewer's timezone, e.g. `"Free 06:00–08:00 EST"`
+    ///
+    /// Dates are included when the gap spans more than one local day, and
+    /// both zone abbreviations are shown when a DST change falls inside it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::gap_validation::TimeGap;
+    /// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+    ///
+    /// let utc = parse_timezone("UTC").unwrap();
+    /// let gap = TimeGap::new(
+    ///     parse_datetime_with_tz("2025-11-10 14:00:00", utc).unwrap(),
+    ///     parse_datetime_with_tz("2025-11-10 15:30:00", utc).unwrap(),
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(gap.describe_in(chrono_tz::Asia::Kolkata), "Free 19:30–21:00 IST");
+    /// ```
+    pub fn describe_in(&self, tz: Tz) -> String {
+        let start = self.start.with_timezone(&tz);
+        let end = self.end.with_timezone(&tz);
+        let time_format = if start.date_naive() == end.date_naive() {
+            "%H:%M"
+        } else {
+            "%Y-%m-%d %H:%M"
+        };
+        let (start_zone, end_zone) = (start.format("%Z").to_string(), end.format("%Z").to_string());
+
+        if start_zone == end_zone {
+            format!("Free {}–{} {}", start.format(time_format), end.format(time_format), end_zone)
+        } else {
+            format!(
+                "Free {} {}–{} {}",
+                start.format(time_format),
+                start_zone,
+                end.format(time_format),
+                end_zone
+            )
+        }
+    }
 }
 
 /// Represents an overlap between two or more events
@@ -184,8 +243,8 @@ pub fn find_gaps(
 
     let mut occurrences = calendar.events_between(start, end)?;
 
-    // Filter out inactive events (e.g. Cancelled)
-    occurrences.retain(|e| e.event.is_active());
+    // Filter out events that don't block time (Cancelled or Transparent)
+    occurrences.retain(|e| e.event.blocks_time());
 
     // Sort by start time
     occurrences.sort_by_key(|o| o.occurrence_time);
@@ -193,18 +252,21 @@ pub fn find_gaps(
     let mut gaps = Vec::new();
     let mut current_time = start;
     let mut last_event_title: Option<String> = None;
+    let mut last_event_status: Option<EventStatus> = None;
 
     for occurrence in occurrences.iter() {
         let event_start = occurrence.occurrence_time;
 
         // Check if there's a gap before this event
         if event_start > current_time {
-            let gap = TimeGap::new(
+            let mut gap = TimeGap::new(
                 current_time,
                 event_start,
                 last_event_title.clone(),
                 Some(occurrence.title().to_string()),
             );
+            gap.before_status = last_event_status.clone();
+            gap.after_status = Some(occurrence.event.status.clone());
 
             if gap.duration >= min_gap_duration {
                 gaps.push(gap);
@@ -216,12 +278,14 @@ pub fn find_gaps(
         if event_end > current_time {
             current_time = event_end;
             last_event_title = Some(occurrence.title().to_string());
+            last_event_status = Some(occurrence.event.status.clone());
         }
     }
 
     // Check for gap at the end
     if end > current_time {
-        let gap = TimeGap::new(current_time, end, last_event_title, None);
+        let mut gap = TimeGap::new(current_time, end, last_event_title, None);
+        gap.before_status = last_event_status;
         if gap.duration >= min_gap_duration {
             gaps.push(gap);
         }
@@ -279,8 +343,8 @@ pub fn find_overlaps(
 
     let mut occurrences = calendar.events_between(start, end)?;
 
-    // Filter out inactive events
-    occurrences.retain(|e| e.event.is_active());
+    // Filter out events that don't block time (Cancelled or Transparent)
+    occurrences.retain(|e| e.event.blocks_time());
 
     // Filter out zero-duration events (where start == end)
     // With zero duration, the END checkpoint is processed as a no-op (event not yet active),
@@ -293,6 +357,9 @@ pub fn find_overlaps(
         return Ok(Vec::new());
     }
 
+    // Resolve each end once; the sweep reads them repeatedly
+    let ends: Vec<DateTime<Tz>> = occurrences.iter().map(|occ| occ.end_time()).collect();
+
     // Sweep Line Algorithm: O(N log N) instead of O(N²)
     //
     // Create checkpoints for each event's start and end.
@@ -309,7 +376,7 @@ pub fn find_overlaps(
         Vec::with_capacity(occurrences.len() * 2);
     for (i, occ) in occurrences.iter().enumerate() {
         checkpoints.push((occ.occurrence_time, false, i)); // START checkpoint
-        checkpoints.push((occ.end_time(), true, i)); // END checkpoint
+        checkpoints.push((ends[i], true, i)); // END checkpoint
     }
 
     // Sort by: (1) time ascending, (2) END before START at equal timestamps.
@@ -333,7 +400,7 @@ pub fn find_overlaps(
 
                 // Calculate the actual overlap region
                 let overlap_start = e1.occurrence_time.max(e2.occurrence_time);
-                let overlap_end = e1.end_time().min(e2.end_time());
+                let overlap_end = ends[idx].min(ends[active_idx]);
 
                 overlaps.push(EventOverlap::new(
                     overlap_start,
@@ -349,6 +416,138 @@ pub fn find_overlaps(
     Ok(overlaps)
 }
 
+/// Total double-booked time in a range
+///
+/// Returns how long two or more blocking events run at the same time,
+/// clipped to `[start, end)`. Unlike summing the durations from
+/// [`find_overlaps`], time covered by three events is counted once.
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, Event, gap_validation};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+/// use chrono::Duration;
+///
+/// let mut cal = Calendar::new("Busy");
+/// for start in ["2025-11-01 09:00:00", "2025-11-01 09:30:00", "2025-11-01 09:45:00"] {
+///     cal.add_event(Event::builder().title("Call").start(start, "UTC").duration_hours(1).build().unwrap());
+/// }
+///
+/// let tz = parse_timezone("UTC").unwrap();
+/// let start = parse_datetime_with_tz("2025-11-01 08:00:00", tz).unwrap();
+/// let end = parse_datetime_with_tz("2025-11-01 18:00:00", tz).unwrap();
+///
+/// // Double-booked from 09:30 to 10:30
+/// let doubled = gap_validation::total_overlap_duration(&cal, start, end).unwrap();
+/// assert_eq!(doubled, Duration::hours(1));
+/// ```
+pub fn total_overlap_duration(
+    calendar: &Calendar,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+) -> Result<Duration> {
+    if start >= end {
+        return Err(crate::error::EventixError::ValidationError(
+            "Start time must be before end time".to_string(),
+        ));
+    }
+
+    let mut occurrences = calendar.events_between(start, end)?;
+
+    // Filter out events that don't block time (Cancelled or Transparent)
+    occurrences.retain(|e| e.event.blocks_time());
+
+    // +1 at each (clipped) start, -1 at each end; ends sort first at ties so
+    // touching events never count as overlapping
+    let mut checkpoints: Vec<(DateTime<Tz>, i32)> = Vec::with_capacity(occurrences.len() * 2);
+    for occ in &occurrences {
+        let occ_start = occ.occurrence_time.max(start);
+        let occ_end = occ.end_time().min(end);
+        if occ_start < occ_end {
+            checkpoints.push((occ_start, 1));
+            checkpoints.push((occ_end, -1));
+        }
+    }
+    checkpoints.sort();
+
+    let mut total = Duration::zero();
+    let mut coverage = 0;
+    let mut previous = start;
+    for (time, delta) in checkpoints {
+        if coverage >= 2 {
+            total += time - previous;
+        }
+        coverage += delta;
+        previous = time;
+    }
+
+    Ok(total)
+}
+
+/// Build a pairwise clash matrix for the calendar's events
+///
+/// Returns an N×N matrix indexed like [`Calendar::events`], where `[i][j]`
+/// is `true` if any occurrence of event `i` overlaps any occurrence of
+/// event `j` within `[start, end)`. The matrix is symmetric, the diagonal is
+/// always `false`, and events that don't block time never conflict.
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, Event, gap_validation};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+///
+/// let mut cal = Calendar::new("Rooms");
+/// for start in ["2025-11-03 09:00:00", "2025-11-03 09:30:00"] {
+///     cal.add_event(Event::builder().title("Booking").start(start, "UTC").duration_hours(1).build().unwrap());
+/// }
+///
+/// let tz = parse_timezone("UTC").unwrap();
+/// let start = parse_datetime_with_tz("2025-11-03 00:00:00", tz).unwrap();
+/// let end = parse_datetime_with_tz("2025-11-04 00:00:00", tz).unwrap();
+///
+/// let matrix = gap_validation::conflict_matrix(&cal, start, end).unwrap();
+/// assert_eq!(matrix, vec![vec![false, true], vec![true, false]]);
+/// ```
+pub fn conflict_matrix(
+    calendar: &Calendar,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+) -> Result<Vec<Vec<bool>>> {
+    if start >= end {
+        return Err(crate::error::EventixError::ValidationError(
+            "Start time must be before end time".to_string(),
+        ));
+    }
+
+    let event_count = calendar.event_count();
+    let mut matrix = vec![vec![false; event_count]; event_count];
+
+    let mut occurrences: Vec<(usize, DateTime<Tz>, DateTime<Tz>)> = calendar
+        .events_between(start, end)?
+        .iter()
+        .filter(|occ| occ.event.blocks_time())
+        .map(|occ| (occ.event_index, occ.occurrence_time, occ.end_time()))
+        .collect();
+    occurrences.sort_by_key(|(_, occ_start, _)| *occ_start);
+
+    // Sweep in start order, comparing each occurrence with those still running
+    let mut active: Vec<(usize, DateTime<Tz>)> = Vec::new();
+    for (index, occ_start, occ_end) in occurrences {
+        active.retain(|(_, active_end)| *active_end > occ_start);
+        for &(other, _) in &active {
+            if other != index {
+                matrix[index][other] = true;
+                matrix[other][index] = true;
+            }
+        }
+        active.push((index, occ_end));
+    }
+
+    Ok(matrix)
+}
+
 /// Calculate schedule density metrics
 ///
 /// # Examples
@@ -389,8 +588,8 @@ pub fn calculate_density(
     let total_duration = end.signed_duration_since(start);
     let mut occurrences = calendar.events_between(start, end)?;
 
-    // Filter out inactive events
-    occurrences.retain(|e| e.event.is_active());
+    // Filter out events that don't block time (Cancelled or Transparent)
+    occurrences.retain(|e| e.event.blocks_time());
 
     // Calculate busy time by merging overlapping intervals to avoid
     // double-counting shared time (which would make free_duration negative).
@@ -429,8 +628,18 @@ pub fn calculate_density(
     let occupancy_percentage =
         (busy_duration.num_seconds() as f64 / total_duration.num_seconds() as f64) * 100.0;
 
-    let gaps = find_gaps(calendar, start, end, Duration::minutes(0))?;
-    let overlaps = find_overlaps(calendar, start, end)?;
+    // Gap and overlap detection are independent passes over the range
+    #[cfg(feature = "rayon")]
+    let (gaps, overlaps) = rayon::join(
+        || find_gaps(calendar, start, end, Duration::minutes(0)),
+        || find_overlaps(calendar, start, end),
+    );
+    #[cfg(not(feature = "rayon"))]
+    let (gaps, overlaps) = (
+        find_gaps(calendar, start, end, Duration::minutes(0)),
+        find_overlaps(calendar, start, end),
+    );
+    let (gaps, overlaps) = (gaps?, overlaps?);
 
     Ok(ScheduleDensity {
         total_duration,
@@ -467,6 +676,209 @@ pub fn find_available_slots(
     find_gaps(calendar, start, end, required_duration)
 }
 
+/// Find the earliest free window of a given duration
+///
+/// Searches `[after, after + within)` and returns the first slot that fits
+/// `duration`, starting at the beginning of the first sufficiently long gap.
+/// Returns `None` when nothing fits inside the horizon.
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, Event, gap_validation};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+/// use chrono::Duration;
+///
+/// let mut cal = Calendar::new("Work");
+/// cal.add_event(
+///     Event::builder()
+///         .title("Standup")
+///         .start("2025-11-03 09:00:00", "UTC")
+///         .duration_minutes(30)
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// let tz = parse_timezone("UTC").unwrap();
+/// let after = parse_datetime_with_tz("2025-11-03 09:00:00", tz).unwrap();
+///
+/// let (start, end) = gap_validation::next_free_slot(&cal, after, Duration::hours(1), Duration::hours(8))
+///     .unwrap()
+///     .unwrap();
+/// assert_eq!(start, parse_datetime_with_tz("2025-11-03 09:30:00", tz).unwrap());
+/// assert_eq!(end, parse_datetime_with_tz("2025-11-03 10:30:00", tz).unwrap());
+/// ```
+pub fn next_free_slot(
+    calendar: &Calendar,
+    after: DateTime<Tz>,
+    duration: Duration,
+    within: Duration,
+) -> Result<Option<(DateTime<Tz>, DateTime<Tz>)>> {
+    if duration <= Duration::zero() {
+        return Err(crate::error::EventixError::ValidationError(
+            "Duration must be greater than zero".to_string(),
+        ));
+    }
+    if within <= Duration::zero() {
+        return Err(crate::error::EventixError::ValidationError(
+            "Search horizon must be greater than zero".to_string(),
+        ));
+    }
+
+    let gaps = find_gaps(calendar, after, after + within, duration)?;
+    Ok(gaps.first().map(|gap| (gap.start, gap.start + duration)))
+}
+
+/// Find the earliest slot of `duration` within business hours on a working day
+///
+/// Walks forward from `after`'s local date (in `after`'s timezone), skipping
+/// Saturdays and Sundays, and searches each day's
+/// `[business_start, business_end)` window (see [`availability_windows`])
+/// for the first gap that fits. On the first day the search begins no
+/// earlier than `after`. At most `max_days` working days are examined.
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, Event, gap_validation};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+/// use chrono::{Duration, NaiveTime};
+///
+/// let mut cal = Calendar::new("Work");
+/// cal.add_event(
+///     Event::builder()
+///         .title("Offsite")
+///         .start("2025-11-07 09:00:00", "UTC")
+///         .duration_hours(8)
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// let tz = parse_timezone("UTC").unwrap();
+/// // Friday is fully booked, so the slot lands on Monday morning
+/// let after = parse_datetime_with_tz("2025-11-07 08:00:00", tz).unwrap();
+/// let (start, _) = gap_validation::earliest_meetable(
+///     &cal,
+///     after,
+///     Duration::hours(1),
+///     NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+///     NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+///     5,
+/// )
+/// .unwrap()
+/// .unwrap();
+/// assert_eq!(start, parse_datetime_with_tz("2025-11-10 09:00:00", tz).unwrap());
+/// ```
+pub fn earliest_meetable(
+    calendar: &Calendar,
+    after: DateTime<Tz>,
+    duration: Duration,
+    business_start: NaiveTime,
+    business_end: NaiveTime,
+    max_days: u32,
+) -> Result<Option<(DateTime<Tz>, DateTime<Tz>)>> {
+    if duration <= Duration::zero() {
+        return Err(crate::error::EventixError::ValidationError(
+            "Duration must be greater than zero".to_string(),
+        ));
+    }
+
+    let tz = after.timezone();
+    let working_days = after
+        .date_naive()
+        .iter_days()
+        .filter(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+        .take(max_days as usize);
+
+    for date in working_days {
+        let windows = availability_windows(date, date, tz, business_start, business_end, true)?;
+        for (window_start, window_end) in windows {
+            let from = window_start.max(after);
+            if window_end - from < duration {
+                continue;
+            }
+            if let Some(gap) = find_gaps(calendar, from, window_end, duration)?.first() {
+                return Ok(Some((gap.start, gap.start + duration)));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Sum the free business-hours time between `start` and `end`
+///
+/// Business hours are interpreted as wall-clock times in `start`'s timezone
+/// on each weekday of the range; weekends are skipped. Time covered by
+/// blocking occurrences is subtracted, and the first and last days are
+/// clipped to `[start, end)`.
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, Event, gap_validation};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+/// use chrono::{Duration, NaiveTime};
+///
+/// let mut cal = Calendar::new("Work");
+/// cal.add_event(
+///     Event::builder()
+///         .title("Review")
+///         .start("2025-11-03 10:00:00", "UTC")
+///         .duration_hours(2)
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// let tz = parse_timezone("UTC").unwrap();
+/// let free = gap_validation::total_free_time(
+///     &cal,
+///     parse_datetime_with_tz("2025-11-03 00:00:00", tz).unwrap(),
+///     parse_datetime_with_tz("2025-11-04 00:00:00", tz).unwrap(),
+///     NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+///     NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+/// )
+/// .unwrap();
+/// assert_eq!(free, Duration::hours(6));
+/// ```
+pub fn total_free_time(
+    calendar: &Calendar,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    business_start: NaiveTime,
+    business_end: NaiveTime,
+) -> Result<Duration> {
+    if start >= end {
+        return Err(crate::error::EventixError::ValidationError(
+            "Start time must be before end time".to_string(),
+        ));
+    }
+
+    let tz = start.timezone();
+    let windows = availability_windows(
+        start.date_naive(),
+        end.with_timezone(&tz).date_naive(),
+        tz,
+        business_start,
+        business_end,
+        true,
+    )?;
+
+    let mut total = Duration::zero();
+    for (window_start, window_end) in windows {
+        let from = window_start.max(start);
+        let to = window_end.min(end);
+        if from >= to {
+            continue;
+        }
+        for gap in find_gaps(calendar, from, to, Duration::zero())? {
+            total += gap.duration;
+        }
+    }
+
+    Ok(total)
+}
+
 /// Check if a time slot is available (no conflicts)
 pub fn is_slot_available(
     calendar: &Calendar,
@@ -480,7 +892,7 @@ pub fn is_slot_available(
     }
 
     for event in calendar.get_events() {
-        if !event.is_active() {
+        if !event.blocks_time() {
             continue;
         }
 
@@ -493,7 +905,7 @@ pub fn is_slot_available(
         let occurrences = event.occurrences_between(query_start, slot_end, 100_000)?;
 
         for occurrence in occurrences {
-            let event_end = occurrence + duration;
+            let event_end = event.occurrence_end(occurrence);
 
             // Check for any overlap between event and slot
             if occurrence < slot_end && slot_start < event_end {
@@ -583,6 +995,514 @@ pub fn suggest_alternatives(
     Ok(suggestions)
 }
 
+/// Suggest alternative times inside business hours, closest first
+///
+/// Like [`suggest_alternatives`], but only proposes slots that fit entirely
+/// within `business_hours` (local open and close times in the timezone of
+/// `requested_start`) on each day of the search window. Candidates are the
+/// start of each free gap, hourly steps through it, and the start nearest to
+/// the requested time. Every suggestion is confirmed with
+/// [`is_slot_available`], and results are ordered by distance from
+/// `requested_start` (earlier first on ties).
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, Event, gap_validation};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+/// use chrono::{Duration, NaiveTime};
+///
+/// let mut cal = Calendar::new("Work");
+/// cal.add_event(
+///     Event::builder()
+///         .title("Review")
+///         .start("2025-11-03 09:00:00", "UTC")
+///         .duration_hours(2)
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// let tz = parse_timezone("UTC").unwrap();
+/// let requested = parse_datetime_with_tz("2025-11-03 09:30:00", tz).unwrap();
+/// let hours = (NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+///
+/// let suggestions = gap_validation::suggest_alternatives_within(
+///     &cal,
+///     requested,
+///     Duration::hours(1),
+///     Duration::hours(4),
+///     hours,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(suggestions[0], parse_datetime_with_tz("2025-11-03 11:00:00", tz).unwrap());
+/// ```
+pub fn suggest_alternatives_within(
+    calendar: &Calendar,
+    requested_start: DateTime<Tz>,
+    duration: Duration,
+    search_window: Duration,
+    business_hours: (NaiveTime, NaiveTime),
+) -> Result<Vec<DateTime<Tz>>> {
+    if duration <= Duration::zero() {
+        return Err(crate::error::EventixError::ValidationError(
+            "Duration must be greater than zero".to_string(),
+        ));
+    }
+    if search_window <= Duration::zero() {
+        return Err(crate::error::EventixError::ValidationError(
+            "Search window must be greater than zero".to_string(),
+        ));
+    }
+
+    let search_start = requested_start - search_window;
+    let search_end = requested_start + search_window;
+    let (open, close) = business_hours;
+
+    let windows = availability_windows(
+        search_start.date_naive(),
+        search_end.date_naive(),
+        requested_start.timezone(),
+        open,
+        close,
+        false,
+    )?;
+
+    let mut suggestions = Vec::new();
+    for (window_start, window_end) in windows {
+        let window_start = window_start.max(search_start);
+        let window_end = window_end.min(search_end);
+        if window_end - window_start < duration {
+            continue;
+        }
+
+        for gap in find_gaps(calendar, window_start, window_end, duration)? {
+            let latest_start = gap.end - duration;
+
+            suggestions.push(gap.start);
+            suggestions.push(requested_start.clamp(gap.start, latest_start));
+
+            let mut slot_start = gap.start + Duration::hours(1);
+            while slot_start <= latest_start {
+                suggestions.push(slot_start);
+                slot_start += Duration::hours(1);
+            }
+        }
+    }
+
+    suggestions.sort();
+    suggestions.dedup();
+
+    let mut available = Vec::with_capacity(suggestions.len());
+    for slot in suggestions {
+        if is_slot_available(calendar, slot, slot + duration)? {
+            available.push(slot);
+        }
+    }
+
+    // Stable sort keeps earlier slots first when equally close
+    available.sort_by_key(|slot| (*slot - requested_start).abs());
+    Ok(available)
+}
+
+/// Find bookable slot start times that respect a minimum notice period
+///
+/// Slots are laid out back-to-back (stepping by `duration`) inside every free
+/// gap of the range, but only slots starting at or after `now + min_notice`
+/// are returned. This is the typical rule for appointment systems where a
+/// booking must be made a few hours in advance.
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, gap_validation};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+/// use chrono::Duration;
+///
+/// let cal = Calendar::new("Appointments");
+/// let tz = parse_timezone("UTC").unwrap();
+/// let now = parse_datetime_with_tz("2025-11-03 12:00:00", tz).unwrap();
+/// let start = parse_datetime_with_tz("2025-11-03 09:00:00", tz).unwrap();
+/// let end = parse_datetime_with_tz("2025-11-03 17:00:00", tz).unwrap();
+///
+/// let slots = gap_validation::bookable_slots(
+///     &cal,
+///     now,
+///     start,
+///     end,
+///     Duration::hours(1),
+///     Duration::hours(2),
+/// )
+/// .unwrap();
+///
+/// // 14:00, 15:00 and 16:00 are far enough in the future
+/// assert_eq!(slots.len(), 3);
+/// ```
+pub fn bookable_slots(
+    calendar: &Calendar,
+    now: DateTime<Tz>,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    duration: Duration,
+    min_notice: Duration,
+) -> Result<Vec<DateTime<Tz>>> {
+    if start >= end {
+        return Err(crate::error::EventixError::ValidationError(
+            "Start time must be before end time".to_string(),
+        ));
+    }
+    if duration <= Duration::zero() {
+        return Err(crate::error::EventixError::ValidationError(
+            "Duration must be greater than zero".to_string(),
+        ));
+    }
+    if min_notice < Duration::zero() {
+        return Err(crate::error::EventixError::ValidationError(
+            "min_notice cannot be negative".to_string(),
+        ));
+    }
+
+    // Nothing before the notice cutoff is bookable, so start the search there.
+    let earliest = start.max(now + min_notice);
+    if earliest >= end {
+        return Ok(Vec::new());
+    }
+
+    let gaps = find_gaps(calendar, earliest, end, duration)?;
+
+    let mut slots = Vec::new();
+    for gap in gaps {
+        let mut slot_start = gap.start;
+        while slot_start + duration <= gap.end {
+            slots.push(slot_start);
+            slot_start += duration;
+        }
+    }
+
+    Ok(slots)
+}
+
+/// Expand a daily availability template into concrete windows
+///
+/// Produces one `[open, close)` window per local day from `start_date` to
+/// `end_date` inclusive, optionally skipping Saturdays and Sundays. Each
+/// day's open and close are resolved from local wall-clock time rather than
+/// by adding a fixed offset to a reference day, so "9:00-17:00
+/// America/New_York" stays 9-5 local across DST transitions while the
+/// underlying UTC instants shift by an hour. Open or close times falling in
+/// a spring-forward gap resolve to the first valid instant after it.
+///
+/// # Examples
+///
+/// ```
+/// use eventix::gap_validation;
+/// use eventix::timezone::parse_timezone;
+/// use chrono::{NaiveDate, NaiveTime};
+///
+/// let tz = parse_timezone("America/New_York").unwrap();
+/// let windows = gap_validation::availability_windows(
+///     NaiveDate::from_ymd_opt(2025, 11, 3).unwrap(),
+///     NaiveDate::from_ymd_opt(2025, 11, 9).unwrap(),
+///     tz,
+///     NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+///     NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+///     true,
+/// )
+/// .unwrap();
+///
+/// // Monday to Friday only
+/// assert_eq!(windows.len(), 5);
+/// ```
+pub fn availability_windows(
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    tz: Tz,
+    open: NaiveTime,
+    close: NaiveTime,
+    skip_weekends: bool,
+) -> Result<Vec<(DateTime<Tz>, DateTime<Tz>)>> {
+    if start_date > end_date {
+        return Err(crate::error::EventixError::ValidationError(
+            "Start date must be before or equal to end date".to_string(),
+        ));
+    }
+    if open >= close {
+        return Err(crate::error::EventixError::ValidationError(
+            "Opening time must be before closing time".to_string(),
+        ));
+    }
+
+    let mut windows = Vec::new();
+    for date in start_date.iter_days().take_while(|d| *d <= end_date) {
+        if skip_weekends && matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            continue;
+        }
+
+        let window_start = resolve_local(tz, date.and_time(open)).ok_or_else(|| {
+            crate::error::EventixError::ValidationError(format!(
+                "Failed to resolve {} {} in {}",
+                date, open, tz
+            ))
+        })?;
+        let window_end = resolve_local(tz, date.and_time(close)).ok_or_else(|| {
+            crate::error::EventixError::ValidationError(format!(
+                "Failed to resolve {} {} in {}",
+                date, close, tz
+            ))
+        })?;
+
+        if window_start < window_end {
+            windows.push((window_start, window_end));
+        }
+    }
+
+    Ok(windows)
+}
+
+/// Find wall-clock windows that are free on every day of a range
+///
+/// Each local day (in `start`'s timezone) touched by `[start, end)` is
+/// examined in full, and the free periods are intersected by local time of
+/// day, so a window is only reported if it is free on all of those days.
+/// Windows shorter than `min_quiet` are dropped. The returned gaps are
+/// placed on the first day of the range and carry no neighbouring event
+/// titles; use their local times as the recurring "do not disturb" slot.
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, Duration, Event, Recurrence, gap_validation};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+///
+/// let mut cal = Calendar::new("Focus");
+/// cal.add_event(
+///     Event::builder()
+///         .title("Work")
+///         .start("2025-11-03 00:00:00", "UTC")
+///         .duration_hours(22)
+///         .recurrence(Recurrence::daily().count(2))
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// let tz = parse_timezone("UTC").unwrap();
+/// let start = parse_datetime_with_tz("2025-11-03 00:00:00", tz).unwrap();
+/// let end = parse_datetime_with_tz("2025-11-05 00:00:00", tz).unwrap();
+///
+/// let quiet = gap_validation::quiet_hours(&cal, start, end, Duration::hours(1)).unwrap();
+/// assert_eq!(quiet.len(), 1);
+/// assert_eq!(quiet[0].duration, Duration::hours(2));
+/// ```
+pub fn quiet_hours(
+    calendar: &Calendar,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    min_quiet: Duration,
+) -> Result<Vec<TimeGap>> {
+    if start >= end {
+        return Err(crate::error::EventixError::ValidationError(
+            "Start time must be before end time".to_string(),
+        ));
+    }
+    if min_quiet < Duration::zero() {
+        return Err(crate::error::EventixError::ValidationError(
+            "min_quiet cannot be negative".to_string(),
+        ));
+    }
+
+    let tz = start.timezone();
+    let first_day = start.date_naive();
+    let last_day = (end - Duration::nanoseconds(1)).date_naive();
+
+    // Free periods as wall-clock offsets from local midnight, intersected day by day
+    let mut quiet: Option<Vec<(Duration, Duration)>> = None;
+    for date in first_day.iter_days().take_while(|d| *d <= last_day) {
+        let (day_start, day_end) = crate::timezone::local_day_window(date, tz)?;
+        let midnight = date.and_time(NaiveTime::MIN);
+        let free: Vec<(Duration, Duration)> =
+            find_gaps(calendar, day_start, day_end, Duration::zero())?
+                .into_iter()
+                .map(|gap| (gap.start.naive_local() - midnight, gap.end.naive_local() - midnight))
+                .collect();
+
+        quiet = Some(match quiet {
+            None => free,
+            Some(previous) => intersect_intervals(&previous, &free),
+        });
+    }
+
+    let midnight = first_day.and_time(NaiveTime::MIN);
+    let mut gaps = Vec::new();
+    for (from, to) in quiet.unwrap_or_default() {
+        if to - from < min_quiet {
+            continue;
+        }
+        let resolve = |offset: Duration| {
+            resolve_local(tz, midnight + offset).ok_or_else(|| {
+                crate::error::EventixError::ValidationError(format!(
+                    "Failed to resolve {} in {}",
+                    midnight + offset,
+                    tz
+                ))
+            })
+        };
+        gaps.push(TimeGap::new(resolve(from)?, resolve(to)?, None, None));
+    }
+
+    Ok(gaps)
+}
+
+/// Intersection of two sorted, non-overlapping interval lists
+fn intersect_intervals(
+    a: &[(Duration, Duration)],
+    b: &[(Duration, Duration)],
+) -> Vec<(Duration, Duration)> {
+    let (mut i, mut j) = (0, 0);
+    let mut result = Vec::new();
+    while i < a.len() && j < b.len() {
+        let from = a[i].0.max(b[j].0);
+        let to = a[i].1.min(b[j].1);
+        if from < to {
+            result.push((from, to));
+        }
+        if a[i].1 < b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
+
+/// Flag consecutive meetings that leave less than `desired_break` between them
+///
+/// Returns `(earlier_title, later_title, shortfall)` tuples, where `shortfall`
+/// is how much the earlier meeting would need to be shortened to create the
+/// desired break. Back-to-back meetings report the full `desired_break`;
+/// overlapping meetings are conflicts rather than break candidates and are
+/// left to [`find_overlaps`].
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, Event, gap_validation};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+/// use chrono::Duration;
+///
+/// let mut cal = Calendar::new("Breaks");
+/// cal.add_event(
+///     Event::builder()
+///         .title("Planning")
+///         .start("2025-11-03 09:00:00", "UTC")
+///         .duration_minutes(58)
+///         .build()
+///         .unwrap(),
+/// );
+/// cal.add_event(
+///     Event::builder()
+///         .title("Review")
+///         .start("2025-11-03 10:00:00", "UTC")
+///         .duration_hours(1)
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// let tz = parse_timezone("UTC").unwrap();
+/// let start = parse_datetime_with_tz("2025-11-03 08:00:00", tz).unwrap();
+/// let end = parse_datetime_with_tz("2025-11-03 18:00:00", tz).unwrap();
+///
+/// let breaks = gap_validation::suggest_breaks(&cal, start, end, Duration::minutes(10)).unwrap();
+/// assert_eq!(breaks[0].2, Duration::minutes(8));
+/// ```
+pub fn suggest_breaks(
+    calendar: &Calendar,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    desired_break: Duration,
+) -> Result<Vec<(String, String, Duration)>> {
+    if desired_break <= Duration::zero() {
+        return Err(crate::error::EventixError::ValidationError(
+            "desired_break must be greater than zero".to_string(),
+        ));
+    }
+
+    let transitions = find_tight_transitions(calendar, start, end, desired_break)?;
+    Ok(transitions
+        .into_iter()
+        .map(|(earlier, later, gap)| (earlier, later, desired_break - gap))
+        .collect())
+}
+
+/// Find consecutive meetings separated by less than `min_gap`
+///
+/// Returns `(earlier_title, later_title, gap)` tuples with the actual time
+/// between the two, which is zero for back-to-back meetings. Where
+/// [`find_gaps`] reports usable free time, this surfaces transitions with no
+/// breathing room. Overlapping meetings are left to [`find_overlaps`].
+///
+/// # Examples
+///
+/// ```
+/// use eventix::{Calendar, Duration, Event, gap_validation};
+/// use eventix::timezone::{parse_datetime_with_tz, parse_timezone};
+///
+/// let mut cal = Calendar::new("Day");
+/// for (title, start, minutes) in [("Sync", "09:00:00", 60), ("Review", "10:05:00", 55)] {
+///     cal.add_event(
+///         Event::builder()
+///             .title(title)
+///             .start(&format!("2025-11-03 {}", start), "UTC")
+///             .duration_minutes(minutes)
+///             .build()
+///             .unwrap(),
+///     );
+/// }
+///
+/// let tz = parse_timezone("UTC").unwrap();
+/// let start = parse_datetime_with_tz("2025-11-03 08:00:00", tz).unwrap();
+/// let end = parse_datetime_with_tz("2025-11-03 18:00:00", tz).unwrap();
+///
+/// let tight =
+///     gap_validation::find_tight_transitions(&cal, start, end, Duration::minutes(15)).unwrap();
+/// assert_eq!(tight, vec![("Sync".to_string(), "Review".to_string(), Duration::minutes(5))]);
+/// ```
+pub fn find_tight_transitions(
+    calendar: &Calendar,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    min_gap: Duration,
+) -> Result<Vec<(String, String, Duration)>> {
+    if start >= end {
+        return Err(crate::error::EventixError::ValidationError(
+            "Start time must be before end time".to_string(),
+        ));
+    }
+
+    let mut occurrences = calendar.events_between(start, end)?;
+    occurrences.retain(|e| e.event.blocks_time());
+    occurrences.sort_by_key(|o| o.occurrence_time);
+
+    let mut transitions = Vec::new();
+    // Latest end seen so far and the title of the meeting that produced it
+    let mut previous: Option<(DateTime<Tz>, String)> = None;
+
+    for occurrence in occurrences.iter() {
+        if let Some((prev_end, ref prev_title)) = previous {
+            let gap = occurrence.occurrence_time.signed_duration_since(prev_end);
+            if gap >= Duration::zero() && gap < min_gap {
+                transitions.push((prev_title.clone(), occurrence.title().to_string(), gap));
+            }
+        }
+
+        let event_end = occurrence.end_time();
+        if previous.as_ref().is_none_or(|(prev_end, _)| event_end > *prev_end) {
+            previous = Some((event_end, occurrence.title().to_string()));
+        }
+    }
+
+    Ok(transitions)
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::len_zero)]
@@ -819,4 +1739,448 @@ mod tests {
         // Overlaps are still detected independently
         assert_eq!(density.overlap_count, 1);
     }
+
+    #[test]
+    fn test_bookable_slots_respects_min_notice() {
+        let cal = create_test_calendar().unwrap();
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let now = parse_datetime_with_tz("2025-11-01 12:00:00", tz).unwrap();
+        let start = parse_datetime_with_tz("2025-11-01 08:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-11-01 18:00:00", tz).unwrap();
+        let cutoff = parse_datetime_with_tz("2025-11-01 14:00:00", tz).unwrap();
+
+        let slots =
+            bookable_slots(&cal, now, start, end, Duration::hours(1), Duration::hours(2)).unwrap();
+
+        // 10:00-12:00 is free but too soon; 15:00-17:00 is busy
+        assert!(!slots.is_empty());
+        assert!(slots.iter().all(|slot| *slot >= cutoff));
+        assert_eq!(slots, vec![cutoff, parse_datetime_with_tz("2025-11-01 17:00:00", tz).unwrap()]);
+    }
+
+    #[test]
+    fn test_bookable_slots_notice_past_range_is_empty() {
+        let cal = create_test_calendar().unwrap();
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let now = parse_datetime_with_tz("2025-11-01 17:30:00", tz).unwrap();
+        let start = parse_datetime_with_tz("2025-11-01 08:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-11-01 18:00:00", tz).unwrap();
+
+        let slots =
+            bookable_slots(&cal, now, start, end, Duration::hours(1), Duration::hours(2)).unwrap();
+        assert!(slots.is_empty());
+    }
+
+    #[test]
+    fn test_suggest_breaks_reports_shortfall() {
+        let mut cal = Calendar::new("Breaks");
+        cal.add_event(
+            Event::builder()
+                .title("Planning")
+                .start("2025-11-01 09:00:00", "UTC")
+                .duration_minutes(58)
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Review")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+        // Plenty of room before this one
+        cal.add_event(
+            Event::builder()
+                .title("Retro")
+                .start("2025-11-01 14:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let start = parse_datetime_with_tz("2025-11-01 08:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-11-01 18:00:00", tz).unwrap();
+
+        let breaks = suggest_breaks(&cal, start, end, Duration::minutes(10)).unwrap();
+        assert_eq!(breaks.len(), 1);
+        assert_eq!(breaks[0], ("Planning".to_string(), "Review".to_string(), Duration::minutes(8)));
+    }
+
+    #[test]
+    fn test_find_tight_transitions_reports_actual_gap() {
+        let mut cal = Calendar::new("Transitions");
+        for (title, start, minutes) in [
+            ("Standup", "09:00:00", 60),
+            ("Design", "10:05:00", 55),
+            ("Interview", "11:00:00", 60),
+            ("Lunch", "13:00:00", 60),
+        ] {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start(&format!("2025-11-01 {}", start), "UTC")
+                    .duration_minutes(minutes)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let start = parse_datetime_with_tz("2025-11-01 08:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-11-01 18:00:00", tz).unwrap();
+
+        let tight = find_tight_transitions(&cal, start, end, Duration::minutes(15)).unwrap();
+        assert_eq!(
+            tight,
+            vec![
+                ("Standup".to_string(), "Design".to_string(), Duration::minutes(5)),
+                ("Design".to_string(), "Interview".to_string(), Duration::zero()),
+            ]
+        );
+        assert!(find_tight_transitions(&cal, end, start, Duration::minutes(15)).is_err());
+    }
+
+    #[test]
+    fn test_next_free_slot_skips_busy_morning() {
+        let mut cal = Calendar::new("Busy Morning");
+        for (title, start, minutes) in
+            [("Standup", "09:00:00", 30), ("Review", "09:45:00", 60), ("Interview", "11:00:00", 60)]
+        {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start(&format!("2025-11-01 {}", start), "UTC")
+                    .duration_minutes(minutes)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let after = parse_datetime_with_tz("2025-11-01 09:00:00", tz).unwrap();
+
+        // The 15-minute gap at 09:30 and the 15-minute gap at 10:45 are too short
+        let slot = next_free_slot(&cal, after, Duration::minutes(45), Duration::hours(8)).unwrap();
+        assert_eq!(
+            slot,
+            Some((
+                parse_datetime_with_tz("2025-11-01 12:00:00", tz).unwrap(),
+                parse_datetime_with_tz("2025-11-01 12:45:00", tz).unwrap(),
+            ))
+        );
+
+        // A 15-minute slot fits in the first gap
+        let slot = next_free_slot(&cal, after, Duration::minutes(15), Duration::hours(8)).unwrap();
+        assert_eq!(slot.unwrap().0, parse_datetime_with_tz("2025-11-01 09:30:00", tz).unwrap());
+
+        // Nothing fits before the horizon ends
+        assert_eq!(
+            next_free_slot(&cal, after, Duration::minutes(45), Duration::hours(2)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_total_free_time_over_work_week() {
+        let mut cal = Calendar::new("Week");
+        for (start, minutes) in [
+            ("2025-11-03 10:00:00", 60),  // Monday
+            ("2025-11-05 13:00:00", 90),  // Wednesday
+            ("2025-11-07 16:30:00", 60),  // Friday, runs past closing
+            ("2025-11-08 10:00:00", 120), // Saturday, ignored
+        ] {
+            cal.add_event(
+                Event::builder()
+                    .title("Meeting")
+                    .start(start, "America/New_York")
+                    .duration_minutes(minutes)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let tz = crate::timezone::parse_timezone("America/New_York").unwrap();
+        let at = |s: &str| parse_datetime_with_tz(s, tz).unwrap();
+        let free = total_free_time(
+            &cal,
+            at("2025-11-03 00:00:00"),
+            at("2025-11-10 00:00:00"),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+        )
+        .unwrap();
+
+        // 5 × 8h of business hours minus 60 + 90 + 30 busy minutes
+        assert_eq!(free, Duration::hours(40) - Duration::minutes(180));
+    }
+
+    #[test]
+    fn test_earliest_meetable_moves_to_next_free_morning() {
+        let mut cal = Calendar::new("Booked");
+        // Tuesday is booked wall to wall within business hours
+        for (title, start) in [("Planning", "09:00:00"), ("Workshop", "12:00:00")] {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start(&format!("2025-11-04 {}", start), "Europe/Berlin")
+                    .duration_hours(3)
+                    .build()
+                    .unwrap(),
+            );
+        }
+        cal.add_event(
+            Event::builder()
+                .title("Retro")
+                .start("2025-11-04 15:00:00", "Europe/Berlin")
+                .duration_hours(2)
+                .build()
+                .unwrap(),
+        );
+
+        let tz = crate::timezone::parse_timezone("Europe/Berlin").unwrap();
+        let at = |s: &str| parse_datetime_with_tz(s, tz).unwrap();
+        let open = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let close = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+
+        let slot = earliest_meetable(
+            &cal,
+            at("2025-11-04 08:00:00"),
+            Duration::minutes(30),
+            open,
+            close,
+            5,
+        )
+        .unwrap();
+        assert_eq!(slot, Some((at("2025-11-05 09:00:00"), at("2025-11-05 09:30:00"))));
+
+        // A search starting late on Friday skips the weekend
+        let slot = earliest_meetable(
+            &cal,
+            at("2025-11-07 16:45:00"),
+            Duration::minutes(30),
+            open,
+            close,
+            5,
+        )
+        .unwrap();
+        assert_eq!(slot.unwrap().0, at("2025-11-10 09:00:00"));
+
+        // Only the booked day is searched
+        assert_eq!(
+            earliest_meetable(
+                &cal,
+                at("2025-11-04 08:00:00"),
+                Duration::minutes(30),
+                open,
+                close,
+                1
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_total_overlap_duration_counts_multiply_covered_time_once() {
+        let mut cal = Calendar::new("Double-booked");
+        for (title, start, minutes) in [
+            ("A", "09:00:00", 120), // 09:00-11:00
+            ("B", "10:00:00", 120), // 10:00-12:00
+            ("C", "10:30:00", 60),  // 10:30-11:30
+            ("D", "12:00:00", 60),  // touches B, no overlap
+        ] {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start(&format!("2025-11-01 {}", start), "UTC")
+                    .duration_minutes(minutes)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let start = parse_datetime_with_tz("2025-11-01 08:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-11-01 18:00:00", tz).unwrap();
+
+        // 2x: 10:00-10:30 and 11:00-11:30; 3x: 10:30-11:00 => 1h30 in total,
+        // whereas the pairwise overlaps add up to 2h30
+        let doubled = total_overlap_duration(&cal, start, end).unwrap();
+        assert_eq!(doubled, Duration::minutes(90));
+
+        let pairwise: Duration =
+            find_overlaps(&cal, start, end).unwrap().iter().map(|o| o.duration).sum();
+        assert_eq!(pairwise, Duration::minutes(150));
+
+        // Clipped to the query range
+        let clip_end = parse_datetime_with_tz("2025-11-01 10:15:00", tz).unwrap();
+        assert_eq!(total_overlap_duration(&cal, start, clip_end).unwrap(), Duration::minutes(15));
+    }
+
+    #[test]
+    fn test_time_gap_max_bookable() {
+        let utc = crate::timezone::parse_timezone("UTC").unwrap();
+        let at = |s: &str| parse_datetime_with_tz(s, utc).unwrap();
+
+        let gap = TimeGap::new(at("2025-11-10 11:00:00"), at("2025-11-10 12:00:00"), None, None);
+        assert_eq!(gap.max_bookable(Duration::minutes(10)), Duration::minutes(40));
+        assert_eq!(gap.max_bookable(Duration::zero()), Duration::minutes(60));
+        // Buffers larger than half the gap leave nothing bookable
+        assert_eq!(gap.max_bookable(Duration::minutes(45)), Duration::zero());
+    }
+
+    #[test]
+    fn test_time_gap_describe_in_viewer_timezone() {
+        let utc = crate::timezone::parse_timezone("UTC").unwrap();
+        let at = |s: &str| parse_datetime_with_tz(s, utc).unwrap();
+        let new_york = crate::timezone::parse_timezone("America/New_York").unwrap();
+
+        let gap = TimeGap::new(at("2025-11-10 11:00:00"), at("2025-11-10 13:00:00"), None, None);
+        assert_eq!(gap.describe_in(new_york), "Free 06:00–08:00 EST");
+        assert_eq!(gap.describe_in(utc), "Free 11:00–13:00 UTC");
+
+        // Crossing local midnight shows dates
+        let late = TimeGap::new(at("2025-11-11 03:00:00"), at("2025-11-11 06:00:00"), None, None);
+        assert_eq!(late.describe_in(new_york), "Free 2025-11-10 22:00–2025-11-11 01:00 EST");
+
+        // A fall-back transition inside the gap shows both abbreviations
+        let dst = TimeGap::new(at("2025-11-02 04:00:00"), at("2025-11-02 08:00:00"), None, None);
+        assert_eq!(dst.describe_in(new_york), "Free 00:00 EDT–03:00 EST");
+    }
+
+    #[test]
+    fn test_conflict_matrix_marks_overlapping_pairs() {
+        let mut cal = Calendar::new("Rooms");
+        for (title, start) in [
+            ("Board", "2025-11-03 09:00:00"),
+            ("Lunch", "2025-11-03 12:00:00"),
+            ("Sales", "2025-11-03 09:30:00"),
+        ] {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start(start, "UTC")
+                    .duration_hours(1)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let start = parse_datetime_with_tz("2025-11-03 00:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-11-04 00:00:00", tz).unwrap();
+
+        let matrix = conflict_matrix(&cal, start, end).unwrap();
+        assert_eq!(
+            matrix,
+            vec![vec![false, false, true], vec![false, false, false], vec![true, false, false]]
+        );
+        for (i, row) in matrix.iter().enumerate() {
+            assert!(!row[i]);
+            for (j, &clash) in row.iter().enumerate() {
+                assert_eq!(clash, matrix[j][i]);
+            }
+        }
+
+        // Cancelling one side clears the clash
+        cal.events[2].cancel();
+        let matrix = conflict_matrix(&cal, start, end).unwrap();
+        assert!(matrix.iter().flatten().all(|&clash| !clash));
+    }
+
+    #[test]
+    fn test_find_gaps_reports_neighbor_status() {
+        let mut cal = Calendar::new("Soft gaps");
+        cal.add_event(
+            Event::builder()
+                .title("Maybe lunch")
+                .start("2025-11-01 12:00:00", "UTC")
+                .duration_hours(1)
+                .status(EventStatus::Tentative)
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Review")
+                .start("2025-11-01 14:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let start = parse_datetime_with_tz("2025-11-01 09:00:00", tz).unwrap();
+        let end = parse_datetime_with_tz("2025-11-01 17:00:00", tz).unwrap();
+
+        let gaps = find_gaps(&cal, start, end, Duration::zero()).unwrap();
+        assert_eq!(gaps.len(), 3);
+        assert_eq!(gaps[0].before_status, None);
+        assert_eq!(gaps[0].after_status, Some(EventStatus::Tentative));
+        assert_eq!(gaps[1].before_status, Some(EventStatus::Tentative));
+        assert_eq!(gaps[1].after_status, Some(EventStatus::Confirmed));
+        assert_eq!(gaps[2].before_status, Some(EventStatus::Confirmed));
+        assert_eq!(gaps[2].after_status, None);
+    }
+
+    #[test]
+    fn test_quiet_hours_reports_lunch_free_every_day() {
+        let mut cal = Calendar::new("Quiet");
+        for day in 3..=5 {
+            for (title, start, hours) in [("Morning", "09:00:00", 3), ("Afternoon", "13:00:00", 4)]
+            {
+                cal.add_event(
+                    Event::builder()
+                        .title(title)
+                        .start(&format!("2025-11-0{} {}", day, start), "America/New_York")
+                        .duration_hours(hours)
+                        .build()
+                        .unwrap(),
+                );
+            }
+        }
+        // Only the middle day has an evening call
+        cal.add_event(
+            Event::builder()
+                .title("Evening call")
+                .start("2025-11-04 18:00:00", "America/New_York")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+
+        let tz = crate::timezone::parse_timezone("America/New_York").unwrap();
+        let at = |s: &str| parse_datetime_with_tz(s, tz).unwrap();
+        let quiet = quiet_hours(
+            &cal,
+            at("2025-11-03 00:00:00"),
+            at("2025-11-06 00:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+
+        let windows: Vec<_> = quiet.iter().map(|g| (g.start, g.end)).collect();
+        assert_eq!(
+            windows,
+            vec![
+                (at("2025-11-03 00:00:00"), at("2025-11-03 09:00:00")),
+                (at("2025-11-03 12:00:00"), at("2025-11-03 13:00:00")),
+                (at("2025-11-03 17:00:00"), at("2025-11-03 18:00:00")),
+                (at("2025-11-03 19:00:00"), at("2025-11-04 00:00:00")),
+            ]
+        );
+
+        // Lunch is too short for a 90-minute threshold
+        let long = quiet_hours(
+            &cal,
+            at("2025-11-03 00:00:00"),
+            at("2025-11-06 00:00:00"),
+            Duration::minutes(90),
+        )
+        .unwrap();
+        assert!(long.iter().all(|g| g.start != at("2025-11-03 12:00:00")));
+    }
 }
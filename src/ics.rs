@@ -2,15 +2,58 @@
 
 use crate::calendar::Calendar;
 use crate::error::{EventixError, Result};
-use crate::event::Event;
-use crate::recurrence::Recurrence;
-use chrono::{DateTime, TimeZone};
+use crate::event::{Classification, Event, EventOverride, EventStatus, Reminder, Transparency};
+use crate::recurrence::{weekday_code, Recurrence};
+use crate::timezone::local_day_window;
+use chrono::{DateTime, Duration, TimeZone, Utc};
 use chrono_tz::Tz;
-use icalendar::{Calendar as ICalendar, Component, Event as IEvent, EventLike, Property};
+use icalendar::{Alarm, Calendar as ICalendar, Component, Event as IEvent, EventLike, Property};
 use rrule::Frequency;
 use std::fs;
 use std::path::Path;
 
+/// Options controlling how lenient ICS import is
+#[derive(Debug, Clone)]
+pub struct IcsImportOptions {
+    /// Title given to events that have no `SUMMARY` (default `"(No title)"`)
+    pub missing_summary_placeholder: String,
+    /// Drop `RECURRENCE-ID` overrides whose recurring event (matched by UID)
+    /// is missing, instead of importing them as standalone events
+    /// (default `false`). Orphans are reported as warnings either way.
+    pub drop_orphan_overrides: bool,
+    /// Swap `DTSTART` and `DTEND` when a feed has them reversed, with a
+    /// warning, instead of skipping the event (default `false`)
+    pub auto_fix_reversed_times: bool,
+}
+
+impl Default for IcsImportOptions {
+    fn default() -> Self {
+        Self {
+            missing_summary_placeholder: "(No title)".to_string(),
+            drop_orphan_overrides: false,
+            auto_fix_reversed_times: false,
+        }
+    }
+}
+
+/// Options controlling ICS export
+#[derive(Debug, Clone, Default)]
+pub struct IcsExportOptions {
+    /// Add an explicit `VALUE=DATE-TIME` parameter to timed `DTSTART` and
+    /// `DTEND` properties (default `false`). It is the RFC 5545 default and
+    /// normally omitted, but some strict importers require it.
+    pub explicit_value_date_time: bool,
+    /// Emit events in start-time order instead of calendar order (default
+    /// `false`). Ties keep calendar order, and overrides still follow their
+    /// series.
+    pub sort_by_start: bool,
+    /// Timestamp written as each event's `DTSTAMP` (default: the moment of
+    /// export). `DTSTAMP` records when the ICS object was produced and is
+    /// independent of the event's own `CREATED` and `LAST-MODIFIED`; fixing
+    /// it makes exports reproducible.
+    pub dtstamp: Option<DateTime<Utc>>,
+}
+
 impl Calendar {
     /// Export this calendar to an ICS file
     ///
@@ -36,8 +79,134 @@ impl Calendar {
             .map_err(|e| EventixError::IcsError(format!("Failed to write ICS file: {}", e)))
     }
 
+    /// Export this calendar to an ICS file without ever leaving it half-written
+    ///
+    /// The content is written and synced to a temporary file in the target's
+    /// directory, which is then renamed over the target. A crash mid-export
+    /// leaves either the old file or the new one, never a partial one. If
+    /// either step fails, the temporary file is removed and an error returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use eventix::Calendar;
+    ///
+    /// let cal = Calendar::new("My Calendar");
+    /// cal.export_to_ics_atomic("calendar.ics").unwrap();
+    /// ```
+    pub fn export_to_ics_atomic<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        use std::io::Write;
+
+        let path = path.as_ref();
+        let ics_content = self.to_ics_string()?;
+
+        let file_name = path.file_name().ok_or_else(|| {
+            EventixError::IcsError(format!("Invalid ICS file path: {}", path.display()))
+        })?;
+        let temp_path = path.with_file_name(format!(
+            ".{}.{}.tmp",
+            file_name.to_string_lossy(),
+            uuid::Uuid::new_v4().simple()
+        ));
+
+        let written = fs::File::create(&temp_path).and_then(|mut file| {
+            file.write_all(ics_content.as_bytes())?;
+            file.sync_all()
+        });
+        if let Err(e) = written {
+            fs::remove_file(&temp_path).ok();
+            return Err(EventixError::IcsError(format!("Failed to write ICS file: {}", e)));
+        }
+
+        fs::rename(&temp_path, path).map_err(|e| {
+            fs::remove_file(&temp_path).ok();
+            EventixError::IcsError(format!("Failed to replace ICS file: {}", e))
+        })
+    }
+
     /// Convert this calendar to an ICS string
     pub fn to_ics_string(&self) -> Result<String> {
+        self.to_ics_string_with_options(&IcsExportOptions::default())
+    }
+
+    /// Convert this calendar to an ICS string using the given export options
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event};
+    /// use eventix::ics::IcsExportOptions;
+    ///
+    /// let mut cal = Calendar::new("Strict");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Sync")
+    ///         .start("2025-11-03 09:00:00", "Europe/Paris")
+    ///         .duration_hours(1)
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let options = IcsExportOptions {
+    ///     explicit_value_date_time: true,
+    ///     ..Default::default()
+    /// };
+    /// let ics = cal.to_ics_string_with_options(&options).unwrap();
+    /// assert!(ics.contains("VALUE=DATE-TIME"));
+    /// ```
+    pub fn to_ics_string_with_options(&self, options: &IcsExportOptions) -> Result<String> {
+        let ics = self.to_ical_with_options(options)?.to_string();
+
+        // Raw sub-components cannot round-trip through `icalendar` verbatim,
+        // so they are spliced into the serialized VEVENTs, which appear in
+        // the same order as `to_ical_with_options` pushes them.
+        let mut raw_blocks: Vec<&[String]> = Vec::new();
+        for event in self.export_order(options) {
+            raw_blocks.push(&event.raw_subcomponents);
+            raw_blocks.extend(std::iter::repeat_n(&[][..], event.overrides.len()));
+        }
+        Ok(splice_raw_subcomponents(ics, &raw_blocks))
+    }
+
+    /// Events in the order they are written on export
+    fn export_order(&self, options: &IcsExportOptions) -> Vec<&Event> {
+        let mut events: Vec<&Event> = self.events.iter().collect();
+        if options.sort_by_start {
+            events.sort_by_key(|event| event.start_time);
+        }
+        events
+    }
+
+    /// Build an [`icalendar::Calendar`] for this calendar
+    ///
+    /// Useful for adding custom properties or components before serializing.
+    ///
+    /// This holds everything [`to_ics_string`](Self::to_ics_string) writes
+    /// except each event's [`raw_subcomponents`](crate::Event::raw_subcomponents)
+    /// (such as `PARTICIPANT` blocks), which `to_ics_string` splices into the
+    /// serialized text verbatim. Serializing the returned calendar yourself
+    /// drops them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::Calendar;
+    /// use icalendar::Property;
+    ///
+    /// let cal = Calendar::new("Team");
+    /// let mut ical = cal.to_ical().unwrap();
+    /// ical.append_property(Property::new("X-PUBLISHED-TTL", "PT1H"));
+    ///
+    /// assert!(ical.to_string().contains("X-PUBLISHED-TTL:PT1H"));
+    /// ```
+    pub fn to_ical(&self) -> Result<ICalendar> {
+        self.to_ical_with_options(&IcsExportOptions::default())
+    }
+
+    /// Like [`to_ical`](Self::to_ical), using the given export options
+    ///
+    /// Raw sub-components are not included; see [`to_ical`](Self::to_ical).
+    pub fn to_ical_with_options(&self, options: &IcsExportOptions) -> Result<ICalendar> {
         let mut ical = ICalendar::new();
 
         // Set calendar properties
@@ -45,14 +214,29 @@ impl Calendar {
         if let Some(ref desc) = self.description {
             ical.description(desc);
         }
+        if let Some(ref url) = self.url {
+            ical.append_property(Property::new("URL", url));
+        }
+        if let Some(ref source) = self.source {
+            ical.append_property(
+                Property::new("SOURCE", source).add_parameter("VALUE", "URI").done(),
+            );
+        }
 
-        // Add each event
-        for event in &self.events {
-            let ical_event = event_to_ical(event)?;
+        // Add each event, followed by its per-occurrence overrides
+        for event in self.export_order(options) {
+            let ical_event = event_to_ical(event, options)?;
+            let uid = ical_event.get_uid().unwrap_or_default().to_string();
             ical.push(ical_event);
+
+            let mut overrides: Vec<_> = event.overrides.iter().collect();
+            overrides.sort_by_key(|(recurrence_id, _)| **recurrence_id);
+            for (recurrence_id, occurrence) in overrides {
+                ical.push(override_to_ical(event, &uid, *recurrence_id, occurrence, options));
+            }
         }
 
-        Ok(ical.to_string())
+        Ok(ical)
     }
 
     /// Import a calendar from an ICS file
@@ -73,13 +257,72 @@ impl Calendar {
     }
 
     /// Parse a calendar from an ICS string
+    ///
+    /// Uses the default [`IcsImportOptions`]. Events that cannot be parsed
+    /// are skipped; use [`from_ics_string_with_report()`](Self::from_ics_string_with_report)
+    /// to find out which.
     pub fn from_ics_string(ics: &str) -> Result<Self> {
+        Self::from_ics_string_with_report(ics).map(|(calendar, _)| calendar)
+    }
+
+    /// Parse a calendar from an ICS string, returning the import warnings
+    ///
+    /// Uses the default [`IcsImportOptions`]. Each skipped event or filled-in
+    /// placeholder produces one warning, so callers can report e.g.
+    /// "3 events skipped" instead of losing the detail. When any `TZID`
+    /// fails to resolve, a final warning lists every distinct `TZID` the
+    /// events referenced, whether it resolved, and how many events used it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::Calendar;
+    ///
+    /// let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nSUMMARY:No start\r\n\
+    ///            END:VEVENT\r\nEND:VCALENDAR\r\n";
+    ///
+    /// let (cal, warnings) = Calendar::from_ics_string_with_report(ics).unwrap();
+    /// assert_eq!(cal.event_count(), 0);
+    /// assert_eq!(warnings.len(), 1);
+    /// ```
+    pub fn from_ics_string_with_report(ics: &str) -> Result<(Self, Vec<String>)> {
+        Self::from_ics_string_with_options(ics, &IcsImportOptions::default())
+    }
+
+    /// Parse a calendar from an ICS string with explicit import options
+    ///
+    /// Returns the calendar together with the warnings recorded while
+    /// importing, such as events that were skipped or had placeholder values
+    /// filled in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::Calendar;
+    /// use eventix::ics::IcsImportOptions;
+    ///
+    /// let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nDTSTART:20251101T100000Z\r\n\
+    ///            DTEND:20251101T110000Z\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+    ///
+    /// let options = IcsImportOptions {
+    ///     missing_summary_placeholder: "Busy".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let (cal, warnings) = Calendar::from_ics_string_with_options(ics, &options).unwrap();
+    /// assert_eq!(cal.events[0].title, "Busy");
+    /// assert_eq!(warnings.len(), 1);
+    /// ```
+    pub fn from_ics_string_with_options(
+        ics: &str,
+        options: &IcsImportOptions,
+    ) -> Result<(Self, Vec<String>)> {
         // Parse the ICS content
         let ical = ics
             .parse::<ICalendar>()
             .map_err(|e| EventixError::IcsError(format!("Failed to parse ICS: {}", e)))?;
 
         let mut calendar = Calendar::new("Imported Calendar");
+        let mut warnings = Vec::new();
 
         // Extract calendar name if available
         if let Some(name) = ical.get_name() {
@@ -90,25 +333,263 @@ impl Calendar {
             calendar.description = Some(desc.to_string());
         }
 
-        // Parse events
-        for component in ical.components {
-            if let icalendar::CalendarComponent::Event(ical_event) = component {
-                match ical_to_event(&ical_event) {
-                    Ok(event) => calendar.add_event(event),
-                    Err(e) => {
-                        eprintln!("Warning: Failed to parse event: {}", e);
-                        // Continue parsing other events
-                    }
+        for property in &ical.properties {
+            match property.key() {
+                "URL" => calendar.url = Some(property.value().to_string()),
+                "SOURCE" => calendar.source = Some(property.value().to_string()),
+                _ => {}
+            }
+        }
+
+        let tzid_summary =
+            tzid_usage_summary(ical.components.iter().filter_map(|component| match component {
+                icalendar::CalendarComponent::Event(ical_event) => Some(ical_event),
+                _ => None,
+            }));
+
+        // Parse events, pairing each with the raw text of its unmodelled
+        // sub-components (VEVENTs are kept in document order)
+        let mut raw_subcomponents = raw_event_subcomponents(ics).into_iter();
+        let mut x_properties = raw_event_x_properties(ics).into_iter();
+        let ical_events = ical.components.into_iter().filter_map(|component| match component {
+            icalendar::CalendarComponent::Event(mut ical_event) => {
+                // The parser keeps one value per X- name, so the full list
+                // is restored as multi-properties
+                for property in x_properties.next().unwrap_or_default() {
+                    ical_event.append_multi_property(property);
+                }
+                Some((ical_event, raw_subcomponents.next().unwrap_or_default()))
+            }
+            _ => None,
+        });
+        import_ical_events(&mut calendar, ical_events, options, &mut warnings);
+        warnings.extend(tzid_summary);
+
+        Ok((calendar, warnings))
+    }
+}
+
+/// Add parsed VEVENTs to `calendar`, attaching `RECURRENCE-ID` overrides to
+/// their recurring series
+///
+/// Each VEVENT comes with the raw text of its unmodelled sub-components,
+/// which is stored on the imported event (overrides do not keep them).
+///
+/// Events that cannot be parsed are skipped with a warning. An orphaned
+/// override, whose series (matched by UID) is missing, is reported as a
+/// warning and then imported standalone or dropped according to
+/// [`IcsImportOptions::drop_orphan_overrides`].
+pub(crate) fn import_ical_events(
+    calendar: &mut Calendar,
+    ical_events: impl IntoIterator<Item = (IEvent, Vec<String>)>,
+    options: &IcsImportOptions,
+    warnings: &mut Vec<String>,
+) {
+    let mut override_events = Vec::new();
+    for (ical_event, raw_subcomponents) in ical_events {
+        // Overrides are attached once every series has been seen
+        if ical_event.properties().contains_key("RECURRENCE-ID") {
+            override_events.push(ical_event);
+            continue;
+        }
+        match ical_to_event(&ical_event, options, warnings) {
+            Ok(mut event) => {
+                event.raw_subcomponents = raw_subcomponents;
+                calendar.add_event(event)
+            }
+            Err(e) => {
+                // Record and continue parsing other events
+                warnings.push(format!("Failed to parse event: {}", e));
+            }
+        }
+    }
+
+    for ical_event in override_events {
+        let uid = ical_event.get_uid();
+        let series = calendar
+            .events
+            .iter_mut()
+            .find(|e| e.recurrence.is_some() && uid.is_some() && e.uid.as_deref() == uid);
+
+        let result = match series {
+            Some(series) => ical_to_override(&ical_event, series).map(|(recurrence_id, o)| {
+                series.overrides.insert(recurrence_id, o);
+            }),
+            None if options.drop_orphan_overrides => {
+                warnings.push(format!(
+                    "Orphan override for {} has no matching recurring event; dropping it",
+                    uid.unwrap_or("event without UID")
+                ));
+                Ok(())
+            }
+            None => {
+                warnings.push(format!(
+                    "Orphan override for {} has no matching recurring event; importing it standalone",
+                    uid.unwrap_or("event without UID")
+                ));
+                ical_to_event(&ical_event, options, warnings).map(|event| calendar.add_event(event))
+            }
+        };
+        if let Err(e) = result {
+            warnings.push(format!("Failed to parse event: {}", e));
+        }
+    }
+}
+
+/// Summarize the distinct `TZID`s referenced by `DTSTART`, `DTEND` and
+/// `RECURRENCE-ID`, in first-seen order
+///
+/// Returns a single warning when at least one of them does not resolve,
+/// e.g. `TZIDs referenced: 'Europe/Paris' resolved (2 events), 'Foo/Bar'
+/// unknown (3 events)`, and nothing otherwise.
+fn tzid_usage_summary<'a>(ical_events: impl IntoIterator<Item = &'a IEvent>) -> Option<String> {
+    let mut usage: Vec<(&str, usize)> = Vec::new();
+    for ical_event in ical_events {
+        let mut seen: Vec<&str> = Vec::new();
+        for name in ["DTSTART", "DTEND", "RECURRENCE-ID"] {
+            let tzid = ical_event
+                .properties()
+                .get(name)
+                .and_then(|prop| prop.params().get("TZID"))
+                .map(|param| param.value());
+            if let Some(tzid) = tzid.filter(|tzid| !seen.contains(tzid)) {
+                seen.push(tzid);
+            }
+        }
+        for tzid in seen {
+            match usage.iter_mut().find(|(name, _)| *name == tzid) {
+                Some((_, count)) => *count += 1,
+                None => usage.push((tzid, 1)),
+            }
+        }
+    }
+
+    let resolved = |tzid: &str| crate::timezone::parse_tzid(tzid).is_ok();
+    if usage.iter().all(|(tzid, _)| resolved(tzid)) {
+        return None;
+    }
+
+    let entries: Vec<String> = usage
+        .iter()
+        .map(|(tzid, count)| {
+            format!(
+                "'{}' {} ({} event{})",
+                tzid,
+                if resolved(tzid) {
+                    "resolved"
+                } else {
+                    "unknown"
+                },
+                count,
+                if *count == 1 {
+                    ""
+                } else {
+                    "s"
+                }
+            )
+        })
+        .collect();
+    Some(format!("TZIDs referenced: {}", entries.join(", ")))
+}
+
+/// Collect, for each VEVENT in document order, the raw text of its nested
+/// components other than `VALARM`
+///
+/// Lines are kept exactly as written (including folded continuations) and
+/// joined with CRLF.
+fn raw_event_subcomponents(ics: &str) -> Vec<Vec<String>> {
+    let mut per_event: Vec<Vec<String>> = Vec::new();
+    let mut stack: Vec<String> = Vec::new();
+    // Stack depth the captured component started at, and its lines so far
+    let mut capture: Option<(usize, Vec<&str>)> = None;
+
+    for line in ics.lines() {
+        let upper = line.trim_end().to_ascii_uppercase();
+        if let Some(name) = upper.strip_prefix("BEGIN:") {
+            if capture.is_none()
+                && stack.last().is_some_and(|parent| parent == "VEVENT")
+                && name != "VALARM"
+            {
+                capture = Some((stack.len(), Vec::new()));
+            }
+            if name == "VEVENT" {
+                per_event.push(Vec::new());
+            }
+            stack.push(name.to_string());
+        } else if upper.starts_with("END:") {
+            stack.pop();
+        }
+
+        if let Some((depth, lines)) = &mut capture {
+            lines.push(line);
+            if stack.len() == *depth {
+                let block = lines.join("\r\n");
+                if let Some(blocks) = per_event.last_mut() {
+                    blocks.push(block);
                 }
+                capture = None;
+            }
+        }
+    }
+
+    per_event
+}
+
+/// Collect, for each VEVENT in document order, its own `X-` properties
+///
+/// Unlike the parsed component, this keeps every occurrence of a repeated
+/// name. Properties of nested components are not included.
+fn raw_event_x_properties(ics: &str) -> Vec<Vec<Property>> {
+    let unfolded = icalendar::parser::unfold(ics);
+    let mut per_event: Vec<Vec<Property>> = Vec::new();
+    let mut stack: Vec<String> = Vec::new();
+
+    for line in unfolded.lines() {
+        let upper = line.trim_end().to_ascii_uppercase();
+        if let Some(name) = upper.strip_prefix("BEGIN:") {
+            if name == "VEVENT" {
+                per_event.push(Vec::new());
+            }
+            stack.push(name.to_string());
+        } else if upper.starts_with("END:") {
+            stack.pop();
+        } else if upper.starts_with("X-") && stack.last().is_some_and(|c| c == "VEVENT") {
+            if let (Some(properties), Ok(parsed)) =
+                (per_event.last_mut(), icalendar::parser::Property::try_from(line))
+            {
+                properties.push(parsed.into());
             }
         }
+    }
+
+    per_event
+}
+
+/// Insert raw sub-components before the `END:VEVENT` of the matching VEVENT
+///
+/// `blocks[i]` belongs to the i-th VEVENT in `ics`.
+fn splice_raw_subcomponents(ics: String, blocks: &[&[String]]) -> String {
+    if blocks.iter().all(|b| b.is_empty()) {
+        return ics;
+    }
 
-        Ok(calendar)
+    let mut out = String::with_capacity(ics.len());
+    let mut vevent = 0;
+    for line in ics.split_inclusive("\r\n") {
+        if line == "END:VEVENT\r\n" {
+            for block in blocks.get(vevent).copied().unwrap_or_default() {
+                out.push_str(block);
+                out.push_str("\r\n");
+            }
+            vevent += 1;
+        }
+        out.push_str(line);
     }
+    out
 }
 
 /// Convert a eventix Event to an iCalendar Event
-fn event_to_ical(event: &Event) -> Result<IEvent> {
+fn event_to_ical(event: &Event, options: &IcsExportOptions) -> Result<IEvent> {
     let mut ical_event = IEvent::new();
 
     // Set UID
@@ -123,8 +604,15 @@ fn event_to_ical(event: &Event) -> Result<IEvent> {
     // Set summary (title)
     ical_event.summary(&event.title);
 
-    // Set description
-    if let Some(ref desc) = event.description {
+    // Set description, carrying any HTML version as an ALTREP data URI
+    if let Some(ref html) = event.description_html {
+        let desc = event.description.as_deref().unwrap_or_default();
+        ical_event.append_property(
+            Property::new("DESCRIPTION", desc)
+                .add_parameter("ALTREP", &html_to_data_uri(html))
+                .done(),
+        );
+    } else if let Some(ref desc) = event.description {
         ical_event.description(desc);
     }
 
@@ -133,31 +621,95 @@ fn event_to_ical(event: &Event) -> Result<IEvent> {
         ical_event.location(loc);
     }
 
+    if let Some(ref language) = event.language {
+        tag_language(&mut ical_event, language);
+    }
+
     // Set start and end times with timezone awareness
-    // If the timezone is UTC, use the standard format without TZID
-    // Otherwise, include TZID parameter for local times
-    let tz_name = event.timezone.name();
+    // UTC uses the Z suffix; other timezones carry a TZID parameter with
+    // local time, e.g. DTSTART;TZID=America/New_York:20251027T100000
+    ical_event.append_property(timed_property(
+        "DTSTART",
+        event.start_time,
+        event.timezone,
+        options,
+    ));
+    ical_event.append_property(timed_property("DTEND", event.end_time, event.timezone, options));
+
+    // Revision tracking: SEQUENCE and DTSTAMP are always emitted so servers
+    // can tell re-imports apart from new events.
+    ical_event.sequence(event.sequence);
+    ical_event.timestamp(options.dtstamp.unwrap_or_else(Utc::now));
+    if let Some(created) = event.created {
+        ical_event.created(created);
+    }
+    if let Some(last_modified) = event.last_modified {
+        ical_event.last_modified(last_modified);
+    }
 
-    if tz_name == "UTC" {
-        // For UTC, use the standard UTC format (with Z suffix)
-        let start_utc = event.start_time.with_timezone(&chrono::Utc);
-        let end_utc = event.end_time.with_timezone(&chrono::Utc);
-        ical_event.starts(start_utc);
-        ical_event.ends(end_utc);
-    } else {
-        // For other timezones, use TZID parameter with local time
-        // Format: DTSTART;TZID=America/New_York:20251027T100000
-        let start_local = event.start_time.format("%Y%m%dT%H%M%S").to_string();
-        let end_local = event.end_time.format("%Y%m%dT%H%M%S").to_string();
+    // CATEGORIES is a TEXT list, and icalendar escapes commas in TEXT values,
+    // so each category is written as its own (equally valid) property line.
+    for category in &event.categories {
+        ical_event.add_multi_property("CATEGORIES", category);
+    }
+
+    // URIs always carry a scheme separator; base64 never contains ':', so a
+    // value without one is an inline binary captured on import.
+    for attachment in &event.attachments {
+        if attachment.contains(':') {
+            ical_event.append_multi_property(Property::new("ATTACH", attachment));
+        } else {
+            ical_event.append_multi_property(
+                Property::new("ATTACH", attachment)
+                    .add_parameter("ENCODING", "BASE64")
+                    .add_parameter("VALUE", "BINARY")
+                    .done(),
+            );
+        }
+    }
+
+    if let Some(priority) = event.priority {
+        ical_event.priority(priority as u32);
+    }
+
+    // OPAQUE is the RFC 5545 default, so only transparent events write TRANSP
+    if event.transparency == Transparency::Transparent {
+        ical_event.add_property("TRANSP", "TRANSPARENT");
+    }
+
+    if let Some(status) = status_to_ical(&event.status) {
+        ical_event.add_property("STATUS", status);
+    }
 
-        // Create properties with TZID parameter
-        let mut dtstart = Property::new("DTSTART", &start_local);
-        dtstart.add_parameter("TZID", tz_name);
-        ical_event.append_property(dtstart);
+    // PUBLIC is the RFC 5545 default, so it is left implicit
+    match event.class {
+        Classification::Public => {}
+        Classification::Private => {
+            ical_event.class(icalendar::Class::Private);
+        }
+        Classification::Confidential => {
+            ical_event.class(icalendar::Class::Confidential);
+        }
+    }
+
+    for reminder in &event.reminders {
+        let (offset, related) = match *reminder {
+            Reminder::RelativeToStart(offset) => (offset, "START"),
+            Reminder::RelativeToEnd(offset) => (offset, "END"),
+        };
+        // Write TRIGGER ourselves so offsets come out as `-PT5M` rather than `-PT300S`
+        let mut alarm = Alarm::display(&event.title, offset);
+        alarm.append_property(
+            Property::new("TRIGGER", format_iso8601_duration(offset))
+                .add_parameter("RELATED", related)
+                .done(),
+        );
+        ical_event.alarm(alarm.done());
+    }
 
-        let mut dtend = Property::new("DTEND", &end_local);
-        dtend.add_parameter("TZID", tz_name);
-        ical_event.append_property(dtend);
+    // Appended rather than set, so repeated names are all written
+    for (name, value) in &event.extra_properties {
+        ical_event.append_multi_property(Property::new(name, value));
     }
 
     // Add attendees
@@ -173,11 +725,14 @@ fn event_to_ical(event: &Event) -> Result<IEvent> {
             let rrule_value = rrule_part.strip_prefix("RRULE:").unwrap_or(rrule_part);
             ical_event.add_property("RRULE", rrule_value);
         }
+    } else if let Some(ref raw_rrule) = event.raw_rrule {
+        ical_event.add_property("RRULE", raw_rrule);
     }
 
     // Add exception dates with timezone (EXDATE is a multi-property in RFC 5545)
     // Normalize each exdate to the event timezone before formatting so the
     // stamped local time matches the TZID label.
+    let tz_name = event.timezone.name();
     let event_tz = event.start_time.timezone();
     for exdate in &event.exdates {
         if tz_name == "UTC" {
@@ -196,16 +751,157 @@ fn event_to_ical(event: &Event) -> Result<IEvent> {
     Ok(ical_event)
 }
 
+/// Convert an occurrence override to a VEVENT carrying `RECURRENCE-ID`
+///
+/// Fields the override leaves unset are copied from the series, since the
+/// override VEVENT replaces the occurrence as a whole.
+fn override_to_ical(
+    event: &Event,
+    uid: &str,
+    recurrence_id: DateTime<Tz>,
+    occurrence: &EventOverride,
+    options: &IcsExportOptions,
+) -> IEvent {
+    let mut ical_event = IEvent::new();
+    ical_event.uid(uid);
+    ical_event.summary(occurrence.title.as_deref().unwrap_or(&event.title));
+    if let Some(desc) = occurrence.description.as_ref().or(event.description.as_ref()) {
+        ical_event.description(desc);
+    }
+    if let Some(loc) = occurrence.location.as_ref().or(event.location.as_ref()) {
+        ical_event.location(loc);
+    }
+    if let Some(ref language) = event.language {
+        tag_language(&mut ical_event, language);
+    }
+
+    ical_event.append_property(ical_datetime_property(
+        "RECURRENCE-ID",
+        recurrence_id,
+        event.timezone,
+    ));
+    ical_event.append_property(timed_property(
+        "DTSTART",
+        occurrence.start_time,
+        event.timezone,
+        options,
+    ));
+    ical_event.append_property(timed_property(
+        "DTEND",
+        occurrence.end_time,
+        event.timezone,
+        options,
+    ));
+    ical_event.sequence(event.sequence);
+    ical_event.timestamp(options.dtstamp.unwrap_or_else(Utc::now));
+
+    if let Some(status) = status_to_ical(&occurrence.status) {
+        ical_event.add_property("STATUS", status);
+    }
+
+    ical_event
+}
+
+/// Add a `LANGUAGE` parameter to the text properties already set on `ical_event`
+fn tag_language(ical_event: &mut IEvent, language: &str) {
+    for name in ["SUMMARY", "DESCRIPTION", "LOCATION"] {
+        if let Some(prop) = ical_event.properties().get(name) {
+            let tagged = prop.clone().add_parameter("LANGUAGE", language).done();
+            ical_event.append_property(tagged);
+        }
+    }
+}
+
+/// Format a datetime property in `tz`: UTC values get the `Z` suffix, other
+/// zones a `TZID` parameter with local time (matching DTSTART/DTEND export)
+fn ical_datetime_property(name: &str, datetime: DateTime<Tz>, tz: Tz) -> Property {
+    if tz.name() == "UTC" {
+        let utc = datetime.with_timezone(&chrono::Utc);
+        Property::new(name, format!("{}Z", utc.format("%Y%m%dT%H%M%S")))
+    } else {
+        let local = datetime.with_timezone(&tz);
+        Property::new(name, local.format("%Y%m%dT%H%M%S").to_string())
+            .add_parameter("TZID", tz.name())
+            .done()
+    }
+}
+
+/// A timed `DTSTART`/`DTEND` property, honouring [`IcsExportOptions`]
+fn timed_property(
+    name: &str,
+    datetime: DateTime<Tz>,
+    tz: Tz,
+    options: &IcsExportOptions,
+) -> Property {
+    let mut property = ical_datetime_property(name, datetime, tz);
+    if options.explicit_value_date_time {
+        property.add_parameter("VALUE", "DATE-TIME");
+    }
+    property
+}
+
+/// Convert a VEVENT carrying `RECURRENCE-ID` into an override of `series`
+///
+/// Returns the original occurrence start together with the override. Fields
+/// equal to the series' values are left unset so later series edits apply.
+fn ical_to_override(ical_event: &IEvent, series: &Event) -> Result<(DateTime<Tz>, EventOverride)> {
+    let (recurrence_id, _) = extract_datetime_with_tz(ical_event, "RECURRENCE-ID")?;
+    let (start_time, _) = extract_datetime_with_tz(ical_event, "DTSTART")?;
+    let (end_time, _) = extract_datetime_with_tz(ical_event, "DTEND")?;
+    if end_time < start_time {
+        return Err(EventixError::ValidationError(
+            "Override end time must be after start time".to_string(),
+        ));
+    }
+
+    let differs = |value: Option<&str>, inherited: Option<&str>| {
+        value.filter(|v| Some(*v) != inherited).map(str::to_string)
+    };
+
+    let mut occurrence = EventOverride::new(start_time, end_time);
+    occurrence.title = differs(ical_event.get_summary(), Some(&series.title));
+    occurrence.description = differs(ical_event.get_description(), series.description.as_deref());
+    occurrence.location = differs(ical_event.get_location(), series.location.as_deref());
+    occurrence.status = status_from_ical(ical_event.property_value("STATUS"));
+
+    Ok((recurrence_id, occurrence))
+}
+
 /// Convert an iCalendar Event to a eventix Event
-fn ical_to_event(ical_event: &IEvent) -> Result<Event> {
-    // Extract required fields
-    let summary = ical_event
-        .get_summary()
-        .ok_or_else(|| EventixError::IcsError("Event missing SUMMARY".to_string()))?;
+fn ical_to_event(
+    ical_event: &IEvent,
+    options: &IcsImportOptions,
+    warnings: &mut Vec<String>,
+) -> Result<Event> {
+    // Some feeds omit SUMMARY for blocked time; keep the event under a placeholder
+    let summary = match ical_event.get_summary() {
+        Some(summary) => summary,
+        None => {
+            warnings.push(format!(
+                "Event {} missing SUMMARY; using '{}'",
+                ical_event.get_uid().unwrap_or("without UID"),
+                options.missing_summary_placeholder
+            ));
+            options.missing_summary_placeholder.as_str()
+        }
+    };
 
     // Try to extract DTSTART and DTEND properties with timezone info
-    let (start_time, _timezone) = extract_datetime_with_tz(ical_event, "DTSTART")?;
-    let (end_time, _) = extract_datetime_with_tz(ical_event, "DTEND")?;
+    let (mut start_time, _timezone) = extract_datetime_with_tz(ical_event, "DTSTART")?;
+    let mut end_time = match extract_datetime_with_tz(ical_event, "DTEND") {
+        Ok((end_time, _)) => end_time,
+        // An all-day (DATE) DTSTART without DTEND lasts one day (RFC 5545),
+        // so it blocks the whole local day like any other opaque event
+        Err(_) if is_date_value(ical_event, "DTSTART") => {
+            local_day_window(start_time.date_naive(), start_time.timezone())?.1
+        }
+        Err(e) => return Err(e),
+    };
+
+    if options.auto_fix_reversed_times && end_time < start_time {
+        warnings.push(format!("Event '{}' has DTEND before DTSTART; swapped them", summary));
+        std::mem::swap(&mut start_time, &mut end_time);
+    }
 
     // Build the event
     let mut builder = Event::builder()
@@ -214,66 +910,361 @@ fn ical_to_event(ical_event: &IEvent) -> Result<Event> {
         .end_datetime(end_time);
 
     // Add optional fields
+    let description_html = ical_event
+        .properties()
+        .get("DESCRIPTION")
+        .and_then(|prop| prop.params().get("ALTREP"))
+        .and_then(|altrep| html_from_data_uri(altrep.value()));
+
     if let Some(desc) = ical_event.get_description() {
-        builder = builder.description(desc);
+        // An HTML-only description is exported with an empty plain-text value
+        if !(desc.is_empty() && description_html.is_some()) {
+            builder = builder.description(desc);
+        }
+    }
+
+    if let Some(html) = description_html {
+        builder = builder.description_html(html);
     }
 
     if let Some(loc) = ical_event.get_location() {
         builder = builder.location(loc);
     }
 
+    // LANGUAGE is per property; take the first text field that carries one
+    let language = ["SUMMARY", "DESCRIPTION", "LOCATION"].iter().find_map(|name| {
+        ical_event.properties().get(*name)?.params().get("LANGUAGE").map(|p| p.value())
+    });
+    if let Some(language) = language {
+        builder = builder.language(language);
+    }
+
     if let Some(uid) = ical_event.get_uid() {
         builder = builder.uid(uid);
     }
 
-    // Parse RRULE if present — reject unsupported rules instead of silently
-    // degrading, since dropping BYMONTH etc. would produce a broader schedule.
-    let props = ical_event.properties();
-    for (key, prop) in props {
-        if key == "RRULE" {
-            let rrule_value = prop.value();
-            let recurrence = parse_rrule_value(rrule_value, start_time)?;
-            builder = builder.recurrence(recurrence);
-        }
+    if let Some(sequence) = ical_event.get_sequence() {
+        builder = builder.sequence(sequence);
     }
 
-    // Parse EXDATE properties (stored in multi_properties per RFC 5545)
-    let event_tz = start_time.timezone();
-    if let Some(exdate_props) = ical_event.multi_properties().get("EXDATE") {
-        for prop in exdate_props {
-            let value = prop.value();
-            // Determine timezone for this EXDATE
-            let exdate_tz = if let Some(tzid_param) = prop.params().get("TZID") {
-                crate::timezone::parse_timezone(tzid_param.value()).unwrap_or(event_tz)
-            } else if value.ends_with('Z') {
-                crate::timezone::parse_timezone("UTC").unwrap_or(event_tz)
-            } else {
-                event_tz
-            };
+    if let Some(created) = ical_event.get_created() {
+        builder = builder.created(created);
+    }
 
-            let dt_str = value.trim_end_matches('Z');
-            let exdate_dt = parse_ical_datetime_value(dt_str, exdate_tz).map_err(|e| {
-                EventixError::IcsError(format!("Failed to parse EXDATE '{}': {}", value, e))
-            })?;
-            builder = builder.exception_date(exdate_dt);
+    if let Some(last_modified) = ical_event.get_last_modified() {
+        builder = builder.last_modified(last_modified);
+    }
+
+    // CATEGORIES may appear multiple times, each holding a comma-separated list
+    if let Some(category_props) = ical_event.multi_properties().get("CATEGORIES") {
+        for prop in category_props {
+            for category in prop.value().split(',') {
+                let category = category.trim();
+                if !category.is_empty() {
+                    builder = builder.category(category);
+                }
+            }
         }
     }
 
-    builder.build()
-}
+    // Inline (ENCODING=BASE64) attachments are kept as the raw base64 string
+    if let Some(attachments) = ical_event.multi_properties().get("ATTACH") {
+        for attachment in attachments {
+            builder = builder.attachment(attachment.value());
+        }
+    }
 
-/// Parse an RRULE value string into a Recurrence.
-///
-/// Supports: FREQ, INTERVAL, COUNT, UNTIL, BYDAY
-fn parse_rrule_value(rrule_str: &str, dtstart: DateTime<Tz>) -> Result<Recurrence> {
-    let mut frequency = None;
-    let mut interval = 1u16;
-    let mut count = None;
-    let mut until = None;
-    let mut by_weekday = None;
+    if ical_event.property_value("TRANSP") == Some("TRANSPARENT") {
+        builder = builder.transparency(Transparency::Transparent);
+    }
 
-    for part in rrule_str.split(';') {
-        let Some((key, value)) = part.split_once('=') else {
+    builder = builder.status(status_from_ical(ical_event.property_value("STATUS")));
+
+    match ical_event.property_value("CLASS") {
+        Some("PRIVATE") => builder = builder.class(Classification::Private),
+        Some("CONFIDENTIAL") => builder = builder.class(Classification::Confidential),
+        _ => {}
+    }
+
+    // Reminders: relative VALARM triggers; RELATED defaults to START.
+    // Absolute (VALUE=DATE-TIME) triggers become an offset from DTSTART so
+    // the reminder model stays relative.
+    for alarm in ical_event.components().iter().filter(|c| c.component_kind() == "VALARM") {
+        let Some(trigger) = alarm.properties().get("TRIGGER") else {
+            continue;
+        };
+        match trigger.params().get("VALUE").map(|v| v.value()) {
+            None | Some("DURATION") => {}
+            Some("DATE-TIME") => {
+                let value = trigger.value();
+                let tz = if value.ends_with('Z') {
+                    Tz::UTC
+                } else {
+                    start_time.timezone()
+                };
+                if let Ok(at) = parse_ical_datetime_value(value.trim_end_matches('Z'), tz) {
+                    builder = builder.reminder(Reminder::RelativeToStart(at - start_time));
+                }
+                continue;
+            }
+            Some(_) => continue,
+        }
+        let Some(offset) = parse_iso8601_duration(trigger.value()) else {
+            continue;
+        };
+        let related_end = trigger.params().get("RELATED").is_some_and(|r| r.value() == "END");
+        builder = builder.reminder(if related_end {
+            Reminder::RelativeToEnd(offset)
+        } else {
+            Reminder::RelativeToStart(offset)
+        });
+    }
+
+    // Vendor X- properties are kept verbatim so they survive re-export. A
+    // repeated name is only complete in the multi-properties.
+    let multi = ical_event.multi_properties();
+    for (key, prop) in ical_event.properties() {
+        if key.starts_with("X-") && !multi.contains_key(key) {
+            builder = builder.extra_property(key, prop.value());
+        }
+    }
+    for (key, props) in multi {
+        if key.starts_with("X-") {
+            for prop in props {
+                builder = builder.extra_property(key, prop.value());
+            }
+        }
+    }
+
+    // Out-of-range priorities are ignored rather than failing the event
+    if let Some(priority) = ical_event.get_priority().and_then(|p| u8::try_from(p).ok()) {
+        if priority <= 9 {
+            builder = builder.priority(priority);
+        }
+    }
+
+    // Parse RRULE if present. Rules with parts beyond the native generator
+    // (BYMONTH, BYSETPOS, ordinal BYDAY, ...) are expanded through `rrule`;
+    // only rules it rejects are kept verbatim rather than degraded, since
+    // dropping parts would produce a broader schedule. Malformed values of
+    // natively handled parts still fail the event.
+    let props = ical_event.properties();
+    for (key, prop) in props {
+        if key == "RRULE" {
+            let rrule_value = prop.value();
+            if let Some(part) = unsupported_rrule_part(rrule_value) {
+                match Recurrence::from_rrule_str(start_time, rrule_value) {
+                    Ok(recurrence) => {
+                        builder = builder.recurrence(normalize_imported_recurrence(
+                            recurrence, start_time, summary, warnings,
+                        ))
+                    }
+                    Err(_) => {
+                        warnings.push(format!(
+                            "Unsupported RRULE part '{}' in '{}'; rule kept verbatim for export, \
+                             only the first occurrence is scheduled",
+                            part, summary
+                        ));
+                        builder = builder.raw_rrule(rrule_value);
+                    }
+                }
+            } else {
+                let recurrence = parse_rrule_value(rrule_value, start_time)?;
+                builder = builder.recurrence(normalize_imported_recurrence(
+                    recurrence, start_time, summary, warnings,
+                ));
+            }
+        }
+    }
+
+    // Parse EXDATE properties (stored in multi_properties per RFC 5545)
+    let event_tz = start_time.timezone();
+    if let Some(exdate_props) = ical_event.multi_properties().get("EXDATE") {
+        for prop in exdate_props {
+            let value = prop.value();
+            // Determine timezone for this EXDATE
+            let exdate_tz = if let Some(tzid_param) = prop.params().get("TZID") {
+                crate::timezone::parse_tzid(tzid_param.value()).unwrap_or(event_tz)
+            } else if value.ends_with('Z') {
+                crate::timezone::parse_timezone("UTC").unwrap_or(event_tz)
+            } else {
+                event_tz
+            };
+
+            let dt_str = value.trim_end_matches('Z');
+            let exdate_dt = parse_ical_datetime_value(dt_str, exdate_tz).map_err(|e| {
+                EventixError::IcsError(format!("Failed to parse EXDATE '{}': {}", value, e))
+            })?;
+            builder = builder.exception_date(exdate_dt);
+        }
+    }
+
+    builder.build()
+}
+
+/// ICS `STATUS` value for an event status
+///
+/// Confirmed and Blocked are left implicit, since a VEVENT without
+/// `STATUS` is treated as confirmed.
+fn status_to_ical(status: &EventStatus) -> Option<&str> {
+    match status {
+        EventStatus::Confirmed | EventStatus::Blocked => None,
+        EventStatus::Tentative => Some("TENTATIVE"),
+        EventStatus::Cancelled => Some("CANCELLED"),
+        EventStatus::Other(value) => Some(value),
+    }
+}
+
+/// Event status for an ICS `STATUS` value, keeping unrecognized values
+///
+/// Besides the VEVENT statuses, two values seen in VTODO-style feeds and
+/// partial invites are mapped onto the closest event status:
+///
+/// | `STATUS`       | [`EventStatus`] |
+/// |----------------|-----------------|
+/// | `NEEDS-ACTION` | `Tentative`     |
+/// | `COMPLETED`    | `Confirmed`     |
+///
+/// Any other value is kept as [`EventStatus::Other`].
+fn status_from_ical(value: Option<&str>) -> EventStatus {
+    match value {
+        None | Some("CONFIRMED") | Some("COMPLETED") => EventStatus::Confirmed,
+        Some("TENTATIVE") | Some("NEEDS-ACTION") => EventStatus::Tentative,
+        Some("CANCELLED") => EventStatus::Cancelled,
+        Some(other) => EventStatus::Other(other.to_string()),
+    }
+}
+
+/// Format a signed duration as an ISO 8601 / RFC 5545 DURATION value (e.g. `-PT5M`)
+pub(crate) fn format_iso8601_duration(duration: Duration) -> String {
+    let sign = if duration < Duration::zero() {
+        "-"
+    } else {
+        ""
+    };
+    let total = duration.num_seconds().unsigned_abs();
+    let (days, hours, minutes, seconds) =
+        (total / 86_400, total % 86_400 / 3600, total % 3600 / 60, total % 60);
+
+    let mut out = format!("{}P", sign);
+    if days > 0 {
+        out.push_str(&format!("{}D", days));
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || days == 0 {
+        out.push('T');
+        if hours > 0 {
+            out.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            out.push_str(&format!("{}M", minutes));
+        }
+        if seconds > 0 || total == 0 {
+            out.push_str(&format!("{}S", seconds));
+        }
+    }
+    out
+}
+
+/// Parse an ISO 8601 / RFC 5545 DURATION value such as `-PT5M`, `PT1H30M`, `P1D`, or `P2W`
+pub(crate) fn parse_iso8601_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (negative, rest) = match value.as_bytes().first()? {
+        b'-' => (true, &value[1..]),
+        b'+' => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let rest = rest.strip_prefix('P')?;
+
+    let mut total = Duration::zero();
+    let mut in_time = false;
+    let mut number = String::new();
+    for c in rest.chars() {
+        match c {
+            'T' if !in_time && number.is_empty() => in_time = true,
+            '0'..='9' => number.push(c),
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match (unit, in_time) {
+                    ('W', false) => Duration::try_weeks(n)?,
+                    ('D', false) => Duration::try_days(n)?,
+                    ('H', true) => Duration::try_hours(n)?,
+                    ('M', true) => Duration::try_minutes(n)?,
+                    ('S', true) => Duration::try_seconds(n)?,
+                    _ => return None,
+                };
+            }
+        }
+    }
+    if !number.is_empty() {
+        return None;
+    }
+
+    Some(if negative {
+        -total
+    } else {
+        total
+    })
+}
+
+/// Encode HTML as a `data:text/html` URI for an ALTREP parameter
+///
+/// Everything but RFC 3986 unreserved characters is percent-encoded, which
+/// also keeps `"` out of the (quoted) parameter value.
+fn html_to_data_uri(html: &str) -> String {
+    let mut uri = String::from("data:text/html,");
+    for byte in html.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    uri
+}
+
+/// Decode the HTML from a percent-encoded `data:text/html` URI
+///
+/// Returns `None` for other URIs (e.g. `cid:` or `http:` references) and for
+/// base64 data URIs, which are not supported.
+fn html_from_data_uri(uri: &str) -> Option<String> {
+    let (header, data) = uri.strip_prefix("data:")?.split_once(',')?;
+    let mut media = header.split(';');
+    if !media.next()?.eq_ignore_ascii_case("text/html")
+        || media.any(|p| p.eq_ignore_ascii_case("base64"))
+    {
+        return None;
+    }
+
+    let bytes = data.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Parse an RRULE value string into a Recurrence.
+///
+/// Supports: FREQ, INTERVAL, COUNT, UNTIL, BYDAY, WKST
+fn parse_rrule_value(rrule_str: &str, dtstart: DateTime<Tz>) -> Result<Recurrence> {
+    let mut frequency = None;
+    let mut interval = 1u16;
+    let mut count = None;
+    let mut until = None;
+    let mut by_weekday = None;
+    let mut week_start = None;
+
+    for part in rrule_str.split(';') {
+        let Some((key, value)) = part.split_once('=') else {
             continue;
         };
         match key {
@@ -312,7 +1303,14 @@ fn parse_rrule_value(rrule_str: &str, dtstart: DateTime<Tz>) -> Result<Recurrenc
                 } else {
                     dtstart.timezone()
                 };
-                until = Some(parse_ical_datetime_value(dt_str, tz)?);
+                let parsed = parse_ical_datetime_value(dt_str, tz)?;
+                // A DATE-only UNTIL covers that whole day (RFC 5545 inclusive)
+                until = Some(if dt_str.len() == 8 {
+                    let (_, next_day) = local_day_window(parsed.date_naive(), tz)?;
+                    next_day - Duration::seconds(1)
+                } else {
+                    parsed
+                });
             }
             "BYDAY" => {
                 let mut weekdays = Vec::new();
@@ -339,6 +1337,24 @@ fn parse_rrule_value(rrule_str: &str, dtstart: DateTime<Tz>) -> Result<Recurrenc
                     by_weekday = Some(weekdays);
                 }
             }
+            "WKST" => {
+                week_start = Some(
+                    [
+                        chrono::Weekday::Mon,
+                        chrono::Weekday::Tue,
+                        chrono::Weekday::Wed,
+                        chrono::Weekday::Thu,
+                        chrono::Weekday::Fri,
+                        chrono::Weekday::Sat,
+                        chrono::Weekday::Sun,
+                    ]
+                    .into_iter()
+                    .find(|wd| weekday_code(*wd) == value)
+                    .ok_or_else(|| {
+                        EventixError::IcsError(format!("Invalid RRULE WKST: {}", value))
+                    })?,
+                );
+            }
             other => {
                 return Err(EventixError::IcsError(format!(
                     "Unsupported RRULE component: {}",
@@ -368,9 +1384,52 @@ fn parse_rrule_value(rrule_str: &str, dtstart: DateTime<Tz>) -> Result<Recurrenc
     if let Some(wd) = by_weekday {
         recurrence = recurrence.weekdays(wd);
     }
+    if let Some(wkst) = week_start {
+        recurrence = recurrence.week_start(wkst);
+    }
     Ok(recurrence)
 }
 
+/// Repair recurrence values that feeds send but [`EventBuilder`](crate::event::EventBuilder)
+/// rejects, with a warning
+///
+/// `INTERVAL=0` is read as the RFC 5545 default of 1, and an `UNTIL` before
+/// `DTSTART` leaves `DTSTART` as the only instance.
+fn normalize_imported_recurrence(
+    mut recurrence: Recurrence,
+    start_time: DateTime<Tz>,
+    summary: &str,
+    warnings: &mut Vec<String>,
+) -> Recurrence {
+    if recurrence.get_interval() == 0 {
+        warnings.push(format!("RRULE INTERVAL=0 in '{}' treated as 1", summary));
+        recurrence = recurrence.interval(1);
+    }
+    if recurrence.get_until().is_some_and(|until| until < start_time) {
+        warnings.push(format!(
+            "RRULE UNTIL in '{}' is before DTSTART; only DTSTART is scheduled",
+            summary
+        ));
+        recurrence = recurrence.until(start_time);
+    }
+    recurrence
+}
+
+/// First part of an RRULE value that [`parse_rrule_value`] cannot represent
+///
+/// Covers unknown keys (`BYMONTH`, `BYWEEKNO`, `BYSETPOS`, ...) and ordinal
+/// `BYDAY` entries like `1MO`, as opposed to malformed values.
+fn unsupported_rrule_part(rrule_str: &str) -> Option<&str> {
+    rrule_str.split(';').find(|part| match part.split_once('=') {
+        Some(("FREQ" | "INTERVAL" | "COUNT" | "UNTIL" | "WKST", _)) | None => false,
+        Some(("BYDAY", days)) => days.split(',').any(|day| {
+            let day = day.trim();
+            day.len() > 2 && day.starts_with(|c: char| c.is_ascii_digit() || c == '+' || c == '-')
+        }),
+        Some(_) => true,
+    })
+}
+
 /// Extract datetime with timezone from an iCalendar property
 fn extract_datetime_with_tz(ical_event: &IEvent, prop_name: &str) -> Result<(DateTime<Tz>, Tz)> {
     // Try to find the property directly from the inner properties
@@ -380,154 +1439,1054 @@ fn extract_datetime_with_tz(ical_event: &IEvent, prop_name: &str) -> Result<(Dat
         if key == prop_name {
             let value = prop.value();
 
-            // Check if there's a TZID parameter
-            let timezone = if let Some(tzid_param) = prop.params().get("TZID") {
-                crate::timezone::parse_timezone(tzid_param.value())?
-            } else if value.ends_with('Z') {
-                // UTC timezone
-                crate::timezone::parse_timezone("UTC")?
-            } else {
-                // Default to UTC if no timezone specified
-                crate::timezone::parse_timezone("UTC")?
-            };
+            // Check if there's a TZID parameter
+            let timezone = if let Some(tzid_param) = prop.params().get("TZID") {
+                crate::timezone::parse_tzid(tzid_param.value())?
+            } else if value.ends_with('Z') {
+                // UTC timezone
+                crate::timezone::parse_timezone("UTC")?
+            } else {
+                // Default to UTC if no timezone specified
+                crate::timezone::parse_timezone("UTC")?
+            };
+
+            // Parse the datetime value (format: 20251027T143000 or 20251027T143000Z)
+            let dt_str = value.trim_end_matches('Z');
+            let datetime = parse_ical_datetime_value(dt_str, timezone)?;
+
+            return Ok((datetime, timezone));
+        }
+    }
+
+    Err(EventixError::IcsError(format!("Property {} not found", prop_name)))
+}
+
+/// Whether a property holds a DATE (all-day) rather than a DATE-TIME value
+fn is_date_value(ical_event: &IEvent, prop_name: &str) -> bool {
+    ical_event.properties().get(prop_name).is_some_and(|prop| {
+        prop.params().get("VALUE").is_some_and(|v| v.value() == "DATE")
+            || (prop.value().len() == 8 && !prop.value().contains('T'))
+    })
+}
+
+/// Parse an iCalendar datetime value string
+///
+/// Accepts both DATE-TIME format (`YYYYMMDDTHHMMSS`, 15+ chars) and
+/// DATE-only format (`YYYYMMDD`, exactly 8 chars). DATE-only values
+/// default to midnight (00:00:00). The minute-precision form
+/// `YYYYMMDDTHHMM` (13 chars) is tolerated with seconds set to 0.
+fn parse_ical_datetime_value(dt_str: &str, tz: Tz) -> Result<DateTime<Tz>> {
+    // DATE-only format: YYYYMMDD (8 chars, no 'T' separator)
+    let (year, month, day, hour, minute, second) = if dt_str.len() == 8 && !dt_str.contains('T') {
+        let year: i32 = dt_str[0..4]
+            .parse()
+            .map_err(|_| EventixError::DateTimeParse(format!("Invalid year in: {}", dt_str)))?;
+        let month: u32 = dt_str[4..6]
+            .parse()
+            .map_err(|_| EventixError::DateTimeParse(format!("Invalid month in: {}", dt_str)))?;
+        let day: u32 = dt_str[6..8]
+            .parse()
+            .map_err(|_| EventixError::DateTimeParse(format!("Invalid day in: {}", dt_str)))?;
+        (year, month, day, 0, 0, 0)
+    } else if dt_str.len() >= 15 || dt_str.len() == 13 {
+        // DATE-TIME format: YYYYMMDDTHHMMSS, or YYYYMMDDTHHMM from producers
+        // that drop the seconds
+        let year: i32 = dt_str[0..4]
+            .parse()
+            .map_err(|_| EventixError::DateTimeParse(format!("Invalid year in: {}", dt_str)))?;
+        let month: u32 = dt_str[4..6]
+            .parse()
+            .map_err(|_| EventixError::DateTimeParse(format!("Invalid month in: {}", dt_str)))?;
+        let day: u32 = dt_str[6..8]
+            .parse()
+            .map_err(|_| EventixError::DateTimeParse(format!("Invalid day in: {}", dt_str)))?;
+        let hour: u32 = dt_str[9..11]
+            .parse()
+            .map_err(|_| EventixError::DateTimeParse(format!("Invalid hour in: {}", dt_str)))?;
+        let minute: u32 = dt_str[11..13]
+            .parse()
+            .map_err(|_| EventixError::DateTimeParse(format!("Invalid minute in: {}", dt_str)))?;
+        let second: u32 = match dt_str.get(13..15) {
+            Some(seconds) => seconds.parse().map_err(|_| {
+                EventixError::DateTimeParse(format!("Invalid second in: {}", dt_str))
+            })?,
+            None => 0,
+        };
+        (year, month, day, hour, minute, second)
+    } else {
+        return Err(EventixError::DateTimeParse(format!("Invalid datetime format: {}", dt_str)));
+    };
+
+    let naive = chrono::NaiveDate::from_ymd_opt(year, month, day)
+        .and_then(|d| d.and_hms_opt(hour, minute, second))
+        .ok_or_else(|| EventixError::DateTimeParse(format!("Invalid datetime: {}", dt_str)))?;
+
+    let dt = tz.from_local_datetime(&naive).earliest().ok_or_else(|| {
+        EventixError::DateTimeParse(format!("Cannot create datetime: {}", dt_str))
+    })?;
+
+    Ok(dt)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use chrono::{Datelike, Timelike};
+
+    #[test]
+    fn test_ics_export() {
+        let mut cal = Calendar::new("Test Calendar");
+        let event = Event::builder()
+            .title("Test Event")
+            .start("2025-11-01 10:00:00", "UTC")
+            .duration_hours(1)
+            .build()
+            .unwrap();
+
+        cal.add_event(event);
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("Test Calendar"));
+        assert!(ics.contains("Test Event"));
+    }
+
+    #[test]
+    fn test_ics_export_explicit_value_date_time() {
+        let mut cal = Calendar::new("Strict");
+        cal.add_event(
+            Event::builder()
+                .title("Local")
+                .start("2025-11-01 10:00:00", "America/New_York")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Utc")
+                .start("2025-11-01 15:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+
+        let plain = cal.to_ics_string().unwrap();
+        assert!(plain.contains("DTSTART;TZID=America/New_York:20251101T100000"));
+        assert!(plain.contains("DTSTART:20251101T150000Z"));
+        assert!(!plain.contains("VALUE=DATE-TIME"));
+
+        let options = IcsExportOptions {
+            explicit_value_date_time: true,
+            ..Default::default()
+        };
+        let strict = cal.to_ics_string_with_options(&options).unwrap();
+        let dtstart = strict
+            .lines()
+            .find(|line| line.starts_with("DTSTART") && line.contains("America/New_York"))
+            .unwrap();
+        assert!(dtstart.contains("VALUE=DATE-TIME"));
+        assert!(dtstart.ends_with(":20251101T100000"));
+        assert!(strict.lines().any(|l| l.starts_with("DTEND") && l.contains("VALUE=DATE-TIME")));
+
+        let reimported = Calendar::from_ics_string(&strict).unwrap();
+        assert_eq!(reimported.events[0].start_time, cal.events[0].start_time);
+        assert_eq!(reimported.events[1].start_time, cal.events[1].start_time);
+    }
+
+    #[test]
+    fn test_ics_export_sorted_by_start() {
+        let mut cal = Calendar::new("Unsorted");
+        for (title, start, tz) in [
+            ("Third", "2025-11-03 09:00:00", "UTC"),
+            ("First", "2025-11-01 09:00:00", "UTC"),
+            // 08:00 in New York is 13:00 UTC, after "First"
+            ("Second", "2025-11-01 08:00:00", "America/New_York"),
+        ] {
+            cal.add_event(
+                Event::builder()
+                    .title(title)
+                    .start(start, tz)
+                    .duration_hours(1)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let summaries = |ics: &str| {
+            ics.lines()
+                .filter_map(|line| line.strip_prefix("SUMMARY:"))
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(summaries(&cal.to_ics_string().unwrap()), vec!["Third", "First", "Second"]);
+
+        let options = IcsExportOptions {
+            sort_by_start: true,
+            ..Default::default()
+        };
+        let sorted = cal.to_ics_string_with_options(&options).unwrap();
+        assert_eq!(summaries(&sorted), vec!["First", "Second", "Third"]);
+    }
+
+    #[test]
+    fn test_ics_rrule_roundtrip() {
+        let mut cal = Calendar::new("RRULE Test");
+        let event = Event::builder()
+            .title("Daily Standup")
+            .start("2025-01-06 09:00:00", "UTC")
+            .duration_minutes(15)
+            .recurrence(Recurrence::daily().interval(2).count(10))
+            .build()
+            .unwrap();
+
+        cal.add_event(event);
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("RRULE:"), "exported ICS should contain RRULE");
+
+        // Re-import
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        assert_eq!(imported.event_count(), 1);
+
+        let imported_event = &imported.events[0];
+        let rec = imported_event.recurrence.as_ref().unwrap();
+        assert_eq!(rec.frequency(), rrule::Frequency::Daily);
+        assert_eq!(rec.get_interval(), 2);
+        assert_eq!(rec.get_count(), Some(10));
+    }
+
+    #[test]
+    fn test_ics_exdate_roundtrip() {
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let exdate = crate::timezone::parse_datetime_with_tz("2025-01-08 09:00:00", tz).unwrap();
+
+        let mut cal = Calendar::new("EXDATE Test");
+        let event = Event::builder()
+            .title("Recurring")
+            .start("2025-01-06 09:00:00", "UTC")
+            .duration_minutes(15)
+            .recurrence(Recurrence::daily().count(10))
+            .exception_date(exdate)
+            .build()
+            .unwrap();
+
+        cal.add_event(event);
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("EXDATE"), "exported ICS should contain EXDATE");
+
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        assert_eq!(imported.events[0].exdates.len(), 1);
+    }
+
+    #[test]
+    fn test_ics_sequence_and_timestamps_roundtrip() {
+        let created = chrono::Utc.with_ymd_and_hms(2025, 10, 1, 8, 0, 0).unwrap();
+        let modified = chrono::Utc.with_ymd_and_hms(2025, 10, 15, 12, 30, 0).unwrap();
+
+        let mut cal = Calendar::new("Revisions");
+        cal.add_event(
+            Event::builder()
+                .title("Revised Meeting")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .sequence(2)
+                .created(created)
+                .last_modified(modified)
+                .build()
+                .unwrap(),
+        );
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("SEQUENCE:2"));
+        assert!(ics.contains("DTSTAMP:"));
+        assert!(ics.contains("CREATED:20251001T080000Z"));
+        assert!(ics.contains("LAST-MODIFIED:20251015T123000Z"));
+
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        let event = &imported.events[0];
+        assert_eq!(event.sequence, 2);
+        assert_eq!(event.created, Some(created));
+        assert_eq!(event.last_modified, Some(modified));
+    }
+
+    #[test]
+    fn test_ics_dtstamp_is_distinct_from_created() {
+        let created = chrono::Utc.with_ymd_and_hms(2025, 10, 1, 8, 0, 0).unwrap();
+        let exported_at = chrono::Utc.with_ymd_and_hms(2025, 11, 20, 17, 45, 0).unwrap();
+
+        let mut cal = Calendar::new("Stamps");
+        cal.add_event(
+            Event::builder()
+                .title("Planned")
+                .uid("planned@x")
+                .start("2025-12-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .created(created)
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Unstamped")
+                .uid("unstamped@x")
+                .start("2025-12-02 10:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+
+        let options = IcsExportOptions {
+            dtstamp: Some(exported_at),
+            ..Default::default()
+        };
+        let ics = cal.to_ics_string_with_options(&options).unwrap();
+        assert_eq!(ics.matches("DTSTAMP:20251120T174500Z").count(), 2);
+        assert_eq!(ics.matches("CREATED:").count(), 1);
+        assert!(ics.contains("CREATED:20251001T080000Z"));
+        // Deterministic across exports
+        assert_eq!(cal.to_ics_string_with_options(&options).unwrap(), ics);
+
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        assert_eq!(imported.events[0].created, Some(created));
+        // DTSTAMP is never mistaken for the creation time
+        assert_eq!(imported.events[1].created, None);
+    }
+
+    #[test]
+    fn test_ics_categories_and_priority_roundtrip() {
+        let mut cal = Calendar::new("Triage");
+        cal.add_event(
+            Event::builder()
+                .title("Incident Review")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .category("Work")
+                .category("Urgent")
+                .priority(1)
+                .build()
+                .unwrap(),
+        );
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("CATEGORIES:Work"));
+        assert!(ics.contains("CATEGORIES:Urgent"));
+        assert!(ics.contains("PRIORITY:1"));
+
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        let event = &imported.events[0];
+        assert_eq!(event.categories, vec!["Work".to_string(), "Urgent".to_string()]);
+        assert_eq!(event.priority, Some(1));
+        assert_eq!(imported.find_events_by_category("urgent").len(), 1);
+    }
+
+    #[test]
+    fn test_ics_description_html_altrep_roundtrip() {
+        let html = r#"<p>Agenda: <b>Q4 "plan"</b> &amp; budget</p>"#;
+        let mut cal = Calendar::new("Rich");
+        cal.add_event(
+            Event::builder()
+                .title("Planning")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .description("Agenda: Q4 plan & budget")
+                .description_html(html)
+                .build()
+                .unwrap(),
+        );
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("DESCRIPTION;ALTREP=\"data:text/html,"));
+
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        let event = &imported.get_events()[0];
+        assert_eq!(event.description.as_deref(), Some("Agenda: Q4 plan & budget"));
+        assert_eq!(event.description_html.as_deref(), Some(html));
+    }
+
+    #[test]
+    fn test_ics_subscription_metadata_roundtrip() {
+        let cal = Calendar::new("Team Holidays")
+            .url("https://example.com/holidays")
+            .source("https://example.com/holidays.ics");
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("SOURCE;VALUE=URI:https://example.com/holidays.ics"));
+        assert!(ics.contains("URL:https://example.com/holidays"));
+
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        assert_eq!(imported.source.as_deref(), Some("https://example.com/holidays.ics"));
+        assert_eq!(imported.url.as_deref(), Some("https://example.com/holidays"));
+    }
+
+    #[test]
+    fn test_to_ical_allows_post_processing() {
+        let mut cal = Calendar::new("Post-processed");
+        cal.add_event(
+            Event::builder()
+                .title("Review")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+
+        let mut ical = cal.to_ical().unwrap();
+        ical.append_property(Property::new("X-WR-RELCALID", "team-42"));
+        let ics = ical.to_string();
+
+        assert!(ics.contains("X-WR-RELCALID:team-42"));
+        assert!(ics.contains("SUMMARY:Review"));
+    }
+
+    #[test]
+    fn test_ics_language_roundtrip() {
+        let mut cal = Calendar::new("International");
+        cal.add_event(
+            Event::builder()
+                .title("Réunion")
+                .start("2025-11-01 10:00:00", "Europe/Paris")
+                .duration_hours(1)
+                .location("Salle 3")
+                .language("fr")
+                .build()
+                .unwrap(),
+        );
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("SUMMARY;LANGUAGE=fr:Réunion"));
+        assert!(ics.contains("LOCATION;LANGUAGE=fr:Salle 3"));
+
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        let event = &imported.get_events()[0];
+        assert_eq!(event.language.as_deref(), Some("fr"));
+        assert_eq!(event.title, "Réunion");
+    }
+
+    #[test]
+    fn test_ics_attachment_roundtrip() {
+        let mut cal = Calendar::new("Attachments");
+        cal.add_event(
+            Event::builder()
+                .title("Review")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .attachment("https://example.com/agenda.pdf")
+                .build()
+                .unwrap(),
+        );
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("ATTACH:https://example.com/agenda.pdf"));
+
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        assert_eq!(
+            imported.get_events()[0].attachments,
+            vec!["https://example.com/agenda.pdf".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_ics_import_splits_comma_separated_categories() {
+        let ics = "\
+BEGIN:VCALENDAR
+BEGIN:VEVENT
+SUMMARY:Tagged
+DTSTART:20251101T100000Z
+DTEND:20251101T110000Z
+CATEGORIES:Work,Urgent
+PRIORITY:12
+END:VEVENT
+END:VCALENDAR";
+
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        let event = &cal.events[0];
+        assert_eq!(event.categories, vec!["Work".to_string(), "Urgent".to_string()]);
+        assert_eq!(event.priority, None);
+    }
+
+    #[test]
+    fn test_ics_transparency_roundtrip() {
+        let mut cal = Calendar::new("Transparency");
+        cal.add_event(
+            Event::builder()
+                .title("Busy")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("FYI")
+                .start("2025-11-01 12:00:00", "UTC")
+                .duration_hours(1)
+                .transparency(Transparency::Transparent)
+                .build()
+                .unwrap(),
+        );
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(!ics.contains("TRANSP:OPAQUE"));
+        assert_eq!(ics.matches("TRANSP:TRANSPARENT").count(), 1);
+
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        assert_eq!(imported.find_events_by_title("Busy")[0].transparency, Transparency::Opaque);
+        assert_eq!(imported.find_events_by_title("FYI")[0].transparency, Transparency::Transparent);
+    }
+
+    #[test]
+    fn test_ics_class_roundtrip() {
+        let mut cal = Calendar::new("Classification");
+        cal.add_event(
+            Event::builder()
+                .title("Team Sync")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Doctor")
+                .start("2025-11-01 12:00:00", "UTC")
+                .duration_hours(1)
+                .class(Classification::Private)
+                .build()
+                .unwrap(),
+        );
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(!ics.contains("CLASS:PUBLIC"));
+        assert_eq!(ics.matches("CLASS:PRIVATE").count(), 1);
+
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        assert_eq!(imported.find_events_by_title("Team Sync")[0].class, Classification::Public);
+        assert_eq!(imported.find_events_by_title("Doctor")[0].class, Classification::Private);
+    }
+
+    #[test]
+    fn test_ics_unknown_status_is_preserved() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:migration@example.com
+SUMMARY:Data migration
+DTSTART:20251101T100000Z
+DTEND:20251101T120000Z
+STATUS:IN-PROCESS
+END:VEVENT
+BEGIN:VEVENT
+UID:maybe@example.com
+SUMMARY:Maybe lunch
+DTSTART:20251101T120000Z
+DTEND:20251101T130000Z
+STATUS:TENTATIVE
+END:VEVENT
+END:VCALENDAR
+";
+
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        let migration = &cal.find_events_by_title("Data migration")[0];
+        assert_eq!(migration.status, EventStatus::Other("IN-PROCESS".to_string()));
+        assert!(migration.is_active());
+        assert_eq!(cal.find_events_by_title("Maybe lunch")[0].status, EventStatus::Tentative);
+
+        let exported = cal.to_ics_string().unwrap();
+        assert!(exported.contains("STATUS:IN-PROCESS"));
+        assert!(exported.contains("STATUS:TENTATIVE"));
+
+        let reimported = Calendar::from_ics_string(&exported).unwrap();
+        assert_eq!(
+            reimported.find_events_by_title("Data migration")[0].status,
+            EventStatus::Other("IN-PROCESS".to_string())
+        );
+    }
+
+    #[test]
+    fn test_ics_needs_action_and_completed_status_mapping() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:invite@example.com
+SUMMARY:Unanswered invite
+DTSTART:20251101T100000Z
+DTEND:20251101T110000Z
+STATUS:NEEDS-ACTION
+END:VEVENT
+BEGIN:VEVENT
+UID:done@example.com
+SUMMARY:Finished review
+DTSTART:20251101T120000Z
+DTEND:20251101T130000Z
+STATUS:COMPLETED
+END:VEVENT
+END:VCALENDAR
+";
+
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        assert_eq!(cal.find_events_by_title("Unanswered invite")[0].status, EventStatus::Tentative);
+        assert_eq!(cal.find_events_by_title("Finished review")[0].status, EventStatus::Confirmed);
+    }
+
+    const WEEKLY_WITH_OVERRIDES: &str = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:standup@example.com
+SUMMARY:Weekly sync
+DTSTART;TZID=America/New_York:20251103T100000
+DTEND;TZID=America/New_York:20251103T110000
+RRULE:FREQ=WEEKLY;COUNT=4
+END:VEVENT
+BEGIN:VEVENT
+UID:standup@example.com
+RECURRENCE-ID;TZID=America/New_York:20251110T100000
+SUMMARY:Weekly sync (moved)
+DTSTART;TZID=America/New_York:20251111T140000
+DTEND;TZID=America/New_York:20251111T150000
+END:VEVENT
+BEGIN:VEVENT
+UID:standup@example.com
+RECURRENCE-ID;TZID=America/New_York:20251117T100000
+SUMMARY:Weekly sync
+DTSTART;TZID=America/New_York:20251117T100000
+DTEND;TZID=America/New_York:20251117T110000
+STATUS:CANCELLED
+END:VEVENT
+END:VCALENDAR";
+
+    #[test]
+    fn test_ics_recurrence_id_overrides_occurrence() {
+        let (cal, warnings) = Calendar::from_ics_string_with_report(WEEKLY_WITH_OVERRIDES).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(cal.event_count(), 1);
+
+        let event = &cal.events[0];
+        assert_eq!(event.overrides.len(), 2);
+
+        let tz = crate::timezone::parse_timezone("America/New_York").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+        let occurrences = event
+            .occurrences_between(at("2025-11-01 00:00:00"), at("2025-12-01 00:00:00"), 10)
+            .unwrap();
+        assert_eq!(
+            occurrences,
+            vec![at("2025-11-03 10:00:00"), at("2025-11-11 14:00:00"), at("2025-11-24 10:00:00")]
+        );
+
+        // Calendar views pick up the moved occurrence's own title and end
+        let moved = cal
+            .events_between(at("2025-11-11 00:00:00"), at("2025-11-12 00:00:00"))
+            .unwrap();
+        assert_eq!(moved.len(), 1);
+        assert_eq!(moved[0].title(), "Weekly sync (moved)");
+        assert_eq!(moved[0].end_time(), at("2025-11-11 15:00:00"));
+    }
+
+    #[test]
+    fn test_ics_recurrence_id_overrides_roundtrip() {
+        let cal = Calendar::from_ics_string(WEEKLY_WITH_OVERRIDES).unwrap();
+
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("RECURRENCE-ID;TZID=America/New_York:20251110T100000"));
+        assert!(ics.contains("STATUS:CANCELLED"));
+
+        let reimported = Calendar::from_ics_string(&ics).unwrap();
+        assert_eq!(reimported.event_count(), 1);
+        assert_eq!(reimported.events[0].overrides, cal.events[0].overrides);
+    }
+
+    #[test]
+    fn test_ics_orphan_override_is_imported_standalone() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:lost@example.com
+RECURRENCE-ID:20251110T100000Z
+SUMMARY:Orphan
+DTSTART:20251111T140000Z
+DTEND:20251111T150000Z
+END:VEVENT
+END:VCALENDAR";
+
+        let (cal, warnings) = Calendar::from_ics_string_with_report(ics).unwrap();
+        assert_eq!(cal.event_count(), 1);
+        assert_eq!(cal.events[0].title, "Orphan");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Orphan override"));
+        assert!(warnings[0].contains("lost@example.com"));
+    }
+
+    #[test]
+    fn test_ics_orphan_override_can_be_dropped() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:lost@example.com
+RECURRENCE-ID:20251110T100000Z
+SUMMARY:Orphan
+DTSTART:20251111T140000Z
+DTEND:20251111T150000Z
+END:VEVENT
+END:VCALENDAR";
+
+        let options = IcsImportOptions {
+            drop_orphan_overrides: true,
+            ..Default::default()
+        };
+        let (cal, warnings) = Calendar::from_ics_string_with_options(ics, &options).unwrap();
+        assert_eq!(cal.event_count(), 0);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Orphan override for lost@example.com"));
+        assert!(warnings[0].contains("dropping"));
+    }
+
+    #[test]
+    fn test_ics_reversed_times_can_be_swapped() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:reversed@example.com
+SUMMARY:Backwards
+DTSTART;TZID=Europe/Paris:20251103T110000
+DTEND;TZID=Europe/Paris:20251103T100000
+END:VEVENT
+END:VCALENDAR";
+
+        let (cal, warnings) = Calendar::from_ics_string_with_report(ics).unwrap();
+        assert_eq!(cal.event_count(), 0);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("Failed to parse event"));
+
+        let options = IcsImportOptions {
+            auto_fix_reversed_times: true,
+            ..Default::default()
+        };
+        let (cal, warnings) = Calendar::from_ics_string_with_options(ics, &options).unwrap();
+        assert_eq!(cal.event_count(), 1);
+        assert_eq!(warnings, vec!["Event 'Backwards' has DTEND before DTSTART; swapped them"]);
+
+        let tz = crate::timezone::parse_timezone("Europe/Paris").unwrap();
+        let event = &cal.events[0];
+        assert_eq!(
+            event.start_time,
+            crate::timezone::parse_datetime_with_tz("2025-11-03 10:00:00", tz).unwrap()
+        );
+        assert_eq!(
+            event.end_time,
+            crate::timezone::parse_datetime_with_tz("2025-11-03 11:00:00", tz).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ics_x_properties_roundtrip() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:vendor@example.com
+SUMMARY:Vendor event
+DTSTART:20251101T100000Z
+DTEND:20251101T110000Z
+X-CUSTOM:foo
+END:VEVENT
+END:VCALENDAR";
+
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        assert_eq!(
+            cal.events[0].extra_properties,
+            vec![("X-CUSTOM".to_string(), "foo".to_string())]
+        );
+
+        let exported = cal.to_ics_string().unwrap();
+        assert!(exported.contains("X-CUSTOM:foo"));
+    }
+
+    #[test]
+    fn test_ics_export_keeps_repeated_x_properties() {
+        let mut cal = Calendar::new("Vendor");
+        cal.add_event(
+            Event::builder()
+                .title("Tagged")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(1)
+                .extra_property("X-VENDOR-TAG", "red")
+                .extra_property("X-VENDOR-TAG", "urgent")
+                .build()
+                .unwrap(),
+        );
+
+        let exported = cal.to_ics_string().unwrap();
+        assert!(exported.contains("X-VENDOR-TAG:red\r\n"));
+        assert!(exported.contains("X-VENDOR-TAG:urgent\r\n"));
+    }
+
+    #[test]
+    fn test_ics_repeated_x_properties_roundtrip() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:tags@example.com
+SUMMARY:Tagged
+DTSTART:20251101T100000Z
+DTEND:20251101T110000Z
+X-TAG:red
+X-TAG:blue
+END:VEVENT
+END:VCALENDAR";
 
-            // Parse the datetime value (format: 20251027T143000 or 20251027T143000Z)
-            let dt_str = value.trim_end_matches('Z');
-            let datetime = parse_ical_datetime_value(dt_str, timezone)?;
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        assert_eq!(
+            cal.events[0].extra_properties,
+            vec![
+                ("X-TAG".to_string(), "red".to_string()),
+                ("X-TAG".to_string(), "blue".to_string()),
+            ]
+        );
 
-            return Ok((datetime, timezone));
-        }
+        let exported = cal.to_ics_string().unwrap();
+        assert!(exported.contains("X-TAG:red\r\n"));
+        assert!(exported.contains("X-TAG:blue\r\n"));
     }
 
-    Err(EventixError::IcsError(format!("Property {} not found", prop_name)))
-}
+    #[test]
+    fn test_ics_end_relative_reminder_roundtrip() {
+        let mut cal = Calendar::new("Reminders");
+        cal.add_event(
+            Event::builder()
+                .title("Workshop")
+                .start("2025-11-01 10:00:00", "UTC")
+                .duration_hours(2)
+                .reminder(Reminder::RelativeToStart(-Duration::minutes(15)))
+                .reminder(Reminder::RelativeToEnd(-Duration::minutes(5)))
+                .build()
+                .unwrap(),
+        );
 
-/// Parse an iCalendar datetime value string
-///
-/// Accepts both DATE-TIME format (`YYYYMMDDTHHMMSS`, 15+ chars) and
-/// DATE-only format (`YYYYMMDD`, exactly 8 chars). DATE-only values
-/// default to midnight (00:00:00).
-fn parse_ical_datetime_value(dt_str: &str, tz: Tz) -> Result<DateTime<Tz>> {
-    // DATE-only format: YYYYMMDD (8 chars, no 'T' separator)
-    let (year, month, day, hour, minute, second) = if dt_str.len() == 8 && !dt_str.contains('T') {
-        let year: i32 = dt_str[0..4]
-            .parse()
-            .map_err(|_| EventixError::DateTimeParse(format!("Invalid year in: {}", dt_str)))?;
-        let month: u32 = dt_str[4..6]
-            .parse()
-            .map_err(|_| EventixError::DateTimeParse(format!("Invalid month in: {}", dt_str)))?;
-        let day: u32 = dt_str[6..8]
-            .parse()
-            .map_err(|_| EventixError::DateTimeParse(format!("Invalid day in: {}", dt_str)))?;
-        (year, month, day, 0, 0, 0)
-    } else if dt_str.len() >= 15 {
-        // DATE-TIME format: YYYYMMDDTHHMMSS
-        let year: i32 = dt_str[0..4]
-            .parse()
-            .map_err(|_| EventixError::DateTimeParse(format!("Invalid year in: {}", dt_str)))?;
-        let month: u32 = dt_str[4..6]
-            .parse()
-            .map_err(|_| EventixError::DateTimeParse(format!("Invalid month in: {}", dt_str)))?;
-        let day: u32 = dt_str[6..8]
-            .parse()
-            .map_err(|_| EventixError::DateTimeParse(format!("Invalid day in: {}", dt_str)))?;
-        let hour: u32 = dt_str[9..11]
-            .parse()
-            .map_err(|_| EventixError::DateTimeParse(format!("Invalid hour in: {}", dt_str)))?;
-        let minute: u32 = dt_str[11..13]
-            .parse()
-            .map_err(|_| EventixError::DateTimeParse(format!("Invalid minute in: {}", dt_str)))?;
-        let second: u32 = dt_str[13..15]
-            .parse()
-            .map_err(|_| EventixError::DateTimeParse(format!("Invalid second in: {}", dt_str)))?;
-        (year, month, day, hour, minute, second)
-    } else {
-        return Err(EventixError::DateTimeParse(format!("Invalid datetime format: {}", dt_str)));
-    };
+        let ics = cal.to_ics_string().unwrap();
+        assert!(ics.contains("BEGIN:VALARM"));
+        assert!(ics.contains("TRIGGER;RELATED=END:-PT5M"));
+        assert!(ics.contains("TRIGGER;RELATED=START:-PT15M"));
 
-    let naive = chrono::NaiveDate::from_ymd_opt(year, month, day)
-        .and_then(|d| d.and_hms_opt(hour, minute, second))
-        .ok_or_else(|| EventixError::DateTimeParse(format!("Invalid datetime: {}", dt_str)))?;
+        let imported = Calendar::from_ics_string(&ics).unwrap();
+        assert_eq!(
+            imported.events[0].reminders,
+            vec![
+                Reminder::RelativeToStart(-Duration::minutes(15)),
+                Reminder::RelativeToEnd(-Duration::minutes(5)),
+            ]
+        );
+    }
 
-    let dt = tz.from_local_datetime(&naive).earliest().ok_or_else(|| {
-        EventixError::DateTimeParse(format!("Cannot create datetime: {}", dt_str))
-    })?;
+    #[test]
+    fn test_ics_participant_subcomponent_round_trips_verbatim() {
+        let participant = "BEGIN:PARTICIPANT\r\n\
+                           UID:speaker-1@example.com\r\n\
+                           PARTICIPANT-TYPE:SPEAKER\r\n\
+                           CALENDAR-ADDRESS:mailto:ada@example.com\r\n\
+                           END:PARTICIPANT";
+        let ics = format!(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VEVENT\r\n\
+             UID:talk@example.com\r\nSUMMARY:Keynote\r\n\
+             DTSTART:20251103T090000Z\r\nDTEND:20251103T100000Z\r\n\
+             {}\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n",
+            participant
+        );
 
-    Ok(dt)
-}
+        let cal = Calendar::from_ics_string(&ics).unwrap();
+        assert_eq!(cal.events[0].raw_subcomponents, vec![participant.to_string()]);
 
-#[cfg(test)]
-mod tests {
-    #![allow(clippy::unwrap_used)]
-    use super::*;
-    use chrono::{Datelike, Timelike};
+        let exported = cal.to_ics_string().unwrap();
+        assert!(exported.contains(&format!("{}\r\nEND:VEVENT\r\n", participant)));
+
+        let reimported = Calendar::from_ics_string(&exported).unwrap();
+        assert_eq!(reimported.events[0].raw_subcomponents, cal.events[0].raw_subcomponents);
+    }
 
     #[test]
-    fn test_ics_export() {
-        let mut cal = Calendar::new("Test Calendar");
-        let event = Event::builder()
-            .title("Test Event")
-            .start("2025-11-01 10:00:00", "UTC")
-            .duration_hours(1)
-            .build()
-            .unwrap();
+    fn test_ics_all_day_out_of_office_blocks_whole_day() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:ooo@example.com
+SUMMARY:Out of Office
+DTSTART;VALUE=DATE:20251105
+END:VEVENT
+BEGIN:VEVENT
+UID:sync@example.com
+SUMMARY:Sync
+DTSTART:20251104T100000Z
+DTEND:20251104T110000Z
+END:VEVENT
+END:VCALENDAR
+";
 
-        cal.add_event(event);
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        assert_eq!(cal.event_count(), 2);
+        let ooo = &cal.events[0];
+        assert_eq!(ooo.duration(), Duration::days(1));
 
-        let ics = cal.to_ics_string().unwrap();
-        assert!(ics.contains("Test Calendar"));
-        assert!(ics.contains("Test Event"));
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let at = |s: &str| crate::timezone::parse_datetime_with_tz(s, tz).unwrap();
+        let slots = |day: &str| {
+            crate::gap_validation::find_available_slots(
+                &cal,
+                at(&format!("{} 09:00:00", day)),
+                at(&format!("{} 17:00:00", day)),
+                Duration::minutes(30),
+            )
+            .unwrap()
+        };
+
+        // Wednesday is fully out of office; Tuesday still has free time
+        assert!(slots("2025-11-05").is_empty());
+        assert_eq!(slots("2025-11-04").len(), 2);
     }
 
     #[test]
-    fn test_ics_rrule_roundtrip() {
-        let mut cal = Calendar::new("RRULE Test");
-        let event = Event::builder()
-            .title("Daily Standup")
-            .start("2025-01-06 09:00:00", "UTC")
-            .duration_minutes(15)
-            .recurrence(Recurrence::daily().interval(2).count(10))
-            .build()
-            .unwrap();
+    fn test_ics_import_reports_tzid_resolution_summary() {
+        let event = |uid: &str, tzid: &str| {
+            format!(
+                "BEGIN:VEVENT\nUID:{uid}\nSUMMARY:{uid}\n\
+                 DTSTART;TZID={tzid}:20251103T090000\n\
+                 DTEND;TZID={tzid}:20251103T100000\nEND:VEVENT\n"
+            )
+        };
+        let ics = format!(
+            "BEGIN:VCALENDAR\nVERSION:2.0\n{}{}{}{}END:VCALENDAR\n",
+            event("ny-1", "America/New_York"),
+            event("bogus", "Foo/Bar"),
+            event("ny-2", "America/New_York"),
+            event("paris", "Europe/Paris"),
+        );
 
-        cal.add_event(event);
+        let (cal, warnings) = Calendar::from_ics_string_with_report(&ics).unwrap();
+        assert_eq!(cal.event_count(), 3);
+        assert_eq!(
+            warnings.last().unwrap(),
+            "TZIDs referenced: 'America/New_York' resolved (2 events), \
+             'Foo/Bar' unknown (1 event), 'Europe/Paris' resolved (1 event)"
+        );
 
-        let ics = cal.to_ics_string().unwrap();
-        assert!(ics.contains("RRULE:"), "exported ICS should contain RRULE");
+        // Nothing is added when every zone resolves
+        let valid = ics.replace("Foo/Bar", "Asia/Tokyo");
+        let (_, warnings) = Calendar::from_ics_string_with_report(&valid).unwrap();
+        assert!(warnings.is_empty());
+    }
 
-        // Re-import
-        let imported = Calendar::from_ics_string(&ics).unwrap();
-        assert_eq!(imported.event_count(), 1);
+    #[test]
+    fn test_ics_import_resolves_windows_tzid() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:Microsoft Exchange Server 2010
+BEGIN:VEVENT
+UID:outlook@example.com
+SUMMARY:Sync
+DTSTART;TZID=Eastern Standard Time:20251103T100000
+DTEND;TZID=Eastern Standard Time:20251103T110000
+END:VEVENT
+END:VCALENDAR
+";
 
-        let imported_event = &imported.events[0];
-        let rec = imported_event.recurrence.as_ref().unwrap();
-        assert_eq!(rec.frequency(), rrule::Frequency::Daily);
-        assert_eq!(rec.get_interval(), 2);
-        assert_eq!(rec.get_count(), Some(10));
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        let event = &cal.events[0];
+        assert_eq!(event.timezone, chrono_tz::America::New_York);
+        assert_eq!(event.start_time.to_rfc3339(), "2025-11-03T10:00:00-05:00");
+
+        let unknown = ics.replace("Eastern Standard Time", "Atlantis Standard Time");
+        let (cal, warnings) = Calendar::from_ics_string_with_report(&unknown).unwrap();
+        assert_eq!(cal.event_count(), 0);
+        assert!(warnings[0].contains("unmapped Windows timezone 'Atlantis Standard Time'"));
     }
 
     #[test]
-    fn test_ics_exdate_roundtrip() {
-        let tz = crate::timezone::parse_timezone("UTC").unwrap();
-        let exdate = crate::timezone::parse_datetime_with_tz("2025-01-08 09:00:00", tz).unwrap();
+    fn test_ics_import_converts_absolute_trigger_to_offset() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:dentist@example.com
+SUMMARY:Dentist
+DTSTART;TZID=Europe/Berlin:20251103T100000
+DTEND;TZID=Europe/Berlin:20251103T103000
+BEGIN:VALARM
+ACTION:DISPLAY
+DESCRIPTION:Leave now
+TRIGGER;VALUE=DATE-TIME:20251103T084500Z
+END:VALARM
+END:VEVENT
+END:VCALENDAR
+";
 
-        let mut cal = Calendar::new("EXDATE Test");
-        let event = Event::builder()
-            .title("Recurring")
-            .start("2025-01-06 09:00:00", "UTC")
-            .duration_minutes(15)
-            .recurrence(Recurrence::daily().count(10))
-            .exception_date(exdate)
-            .build()
-            .unwrap();
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        let event = &cal.events[0];
+        // 08:45Z is 09:45 in Berlin, fifteen minutes before DTSTART
+        let at = crate::timezone::parse_datetime_with_tz("2025-11-03 08:45:00", Tz::UTC).unwrap();
+        assert_eq!(event.reminders, vec![Reminder::RelativeToStart(at - event.start_time)]);
+        assert_eq!(event.reminders, vec![Reminder::RelativeToStart(-Duration::minutes(15))]);
+    }
 
-        cal.add_event(event);
+    #[test]
+    fn test_ics_import_honours_trigger_related_param() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:exam@example.com
+SUMMARY:Exam
+DTSTART:20251101T090000Z
+DTEND:20251101T120000Z
+BEGIN:VALARM
+ACTION:DISPLAY
+DESCRIPTION:Exam starts
+TRIGGER:-PT30M
+END:VALARM
+BEGIN:VALARM
+ACTION:DISPLAY
+DESCRIPTION:Pens down soon
+TRIGGER;RELATED=END:-PT10M
+END:VALARM
+BEGIN:VALARM
+ACTION:DISPLAY
+DESCRIPTION:Hand in
+TRIGGER;RELATED=END:PT0S
+END:VALARM
+END:VEVENT
+END:VCALENDAR
+";
 
-        let ics = cal.to_ics_string().unwrap();
-        assert!(ics.contains("EXDATE"), "exported ICS should contain EXDATE");
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        assert_eq!(
+            cal.events[0].reminders,
+            vec![
+                Reminder::RelativeToStart(-Duration::minutes(30)),
+                Reminder::RelativeToEnd(-Duration::minutes(10)),
+                Reminder::RelativeToEnd(Duration::zero()),
+            ]
+        );
 
-        let imported = Calendar::from_ics_string(&ics).unwrap();
-        assert_eq!(imported.events[0].exdates.len(), 1);
+        // RELATED is always written explicitly on re-export
+        let exported = cal.to_ics_string().unwrap();
+        assert!(exported.contains("TRIGGER;RELATED=START:-PT30M"));
+        assert!(exported.contains("TRIGGER;RELATED=END:-PT10M"));
+    }
+
+    #[test]
+    fn test_ical_duration_format_and_parse() {
+        for (duration, text) in [
+            (-Duration::minutes(5), "-PT5M"),
+            (Duration::hours(1) + Duration::minutes(30), "PT1H30M"),
+            (Duration::days(1), "P1D"),
+            (-(Duration::days(2) + Duration::seconds(15)), "-P2DT15S"),
+            (Duration::zero(), "PT0S"),
+        ] {
+            assert_eq!(format_iso8601_duration(duration), text);
+            assert_eq!(parse_iso8601_duration(text), Some(duration));
+        }
+        assert_eq!(parse_iso8601_duration("-P1W"), Some(-Duration::weeks(1)));
+        assert_eq!(parse_iso8601_duration("PT300S"), Some(Duration::minutes(5)));
+        assert_eq!(parse_iso8601_duration("P5M"), None);
+        assert_eq!(parse_iso8601_duration("PT5"), None);
+        assert_eq!(parse_iso8601_duration("5M"), None);
     }
 
     #[test]
@@ -543,6 +2502,10 @@ mod tests {
 
         // FREQ + INTERVAL + BYDAY
         let rec = parse_rrule_value("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE,FR", start).unwrap();
+        assert_eq!(rec.get_week_start(), chrono::Weekday::Mon);
+        let wkst = parse_rrule_value("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,SU;WKST=SU", start).unwrap();
+        assert_eq!(wkst.get_week_start(), chrono::Weekday::Sun);
+        assert!(parse_rrule_value("FREQ=WEEKLY;WKST=XX", start).is_err());
         assert_eq!(rec.get_interval(), 2);
         let wd = rec.get_weekdays().unwrap();
         assert_eq!(wd.len(), 3);
@@ -557,6 +2520,12 @@ mod tests {
         // FREQ + UNTIL with DATE-only format (no time component)
         let rec = parse_rrule_value("FREQ=DAILY;UNTIL=20250201", start).unwrap();
         assert!(rec.get_until().is_some());
+        // ...which includes occurrences later on that day
+        let last = rec.occurrences(start).last().unwrap();
+        assert_eq!(
+            last,
+            crate::timezone::parse_datetime_with_tz("2025-02-01 10:00:00", tz).unwrap()
+        );
     }
 
     #[test]
@@ -590,6 +2559,139 @@ mod tests {
         assert!(err_msg.contains("COUNT") && err_msg.contains("UNTIL"));
     }
 
+    #[test]
+    fn test_ics_import_expands_bymonthday_rule() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:payroll@example.com
+SUMMARY:Payroll
+DTSTART:20250115T090000Z
+DTEND:20250115T100000Z
+RRULE:FREQ=MONTHLY;BYMONTHDAY=15;COUNT=4
+END:VEVENT
+BEGIN:VEVENT
+UID:week20@example.com
+SUMMARY:Week 20 review
+DTSTART:20250512T090000Z
+DTEND:20250512T100000Z
+RRULE:FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO
+END:VEVENT
+END:VCALENDAR
+";
+
+        let (cal, warnings) = Calendar::from_ics_string_with_report(ics).unwrap();
+        assert!(warnings.is_empty());
+
+        let payroll = &cal.find_events_by_title("Payroll")[0];
+        assert!(payroll.raw_rrule.is_none());
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-01-01 00:00:00", tz).unwrap();
+        let end = crate::timezone::parse_datetime_with_tz("2026-01-01 00:00:00", tz).unwrap();
+        let dates: Vec<_> = payroll
+            .occurrences_between(start, end, 10)
+            .unwrap()
+            .iter()
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .collect();
+        assert_eq!(dates, vec!["2025-01-15", "2025-02-15", "2025-03-15", "2025-04-15"]);
+
+        // Later occurrences block time for gap finding
+        let april = crate::timezone::parse_datetime_with_tz("2025-04-15 08:00:00", tz).unwrap();
+        let gaps = crate::gap_validation::find_gaps(
+            &cal,
+            april,
+            april + Duration::hours(3),
+            Duration::zero(),
+        )
+        .unwrap();
+        assert_eq!(gaps.len(), 2);
+
+        let review = &cal.find_events_by_title("Week 20 review")[0];
+        assert!(review.recurrence.is_some());
+        assert_eq!(
+            review.nth_occurrence(1).unwrap().unwrap().format("%Y-%m-%d").to_string(),
+            "2026-05-11"
+        );
+    }
+
+    #[test]
+    fn test_ics_import_keeps_unsupported_rrule_verbatim() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:easter@example.com
+SUMMARY:Easter brunch
+DTSTART:20250420T090000Z
+DTEND:20250420T100000Z
+RRULE:FREQ=YEARLY;BYEASTER=0
+END:VEVENT
+BEGIN:VEVENT
+UID:broken@example.com
+SUMMARY:Broken
+DTSTART:20250512T090000Z
+DTEND:20250512T100000Z
+RRULE:FREQ=DAILY;COUNT=abc
+END:VEVENT
+END:VCALENDAR
+";
+
+        let (cal, warnings) = Calendar::from_ics_string_with_report(ics).unwrap();
+        assert_eq!(cal.event_count(), 1);
+        assert!(warnings.iter().any(|w| w.contains("BYEASTER=0") && w.contains("Easter brunch")));
+        // Malformed values still fail the event
+        assert!(warnings.iter().any(|w| w.contains("Invalid RRULE COUNT")));
+
+        let event = &cal.events[0];
+        assert!(event.recurrence.is_none());
+        assert_eq!(event.raw_rrule.as_deref(), Some("FREQ=YEARLY;BYEASTER=0"));
+
+        let exported = cal.to_ics_string().unwrap();
+        assert!(exported.contains("RRULE:FREQ=YEARLY;BYEASTER=0"));
+
+        let reimported = Calendar::from_ics_string(&exported).unwrap();
+        assert_eq!(reimported.events[0].raw_rrule, event.raw_rrule);
+
+        assert_eq!(unsupported_rrule_part("FREQ=MONTHLY;BYDAY=-1FR"), Some("BYDAY=-1FR"));
+        assert_eq!(unsupported_rrule_part("FREQ=WEEKLY;BYDAY=MO,FR;WKST=SU"), None);
+    }
+
+    #[test]
+    fn test_ics_import_repairs_zero_interval_and_early_until() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:zero@example.com
+SUMMARY:Zero interval
+DTSTART:20250512T090000Z
+DTEND:20250512T100000Z
+RRULE:FREQ=DAILY;INTERVAL=0;COUNT=3
+END:VEVENT
+BEGIN:VEVENT
+UID:early@example.com
+SUMMARY:Early until
+DTSTART:20250512T090000Z
+DTEND:20250512T100000Z
+RRULE:FREQ=DAILY;UNTIL=20250501T090000Z
+END:VEVENT
+END:VCALENDAR
+";
+
+        let (cal, warnings) = Calendar::from_ics_string_with_report(ics).unwrap();
+        assert_eq!(cal.event_count(), 2);
+        assert!(warnings.iter().any(|w| w.contains("INTERVAL=0") && w.contains("Zero interval")));
+        assert!(warnings.iter().any(|w| w.contains("UNTIL") && w.contains("Early until")));
+
+        let zero = &cal.find_events_by_title("Zero interval")[0];
+        assert_eq!(zero.recurrence.as_ref().unwrap().get_interval(), 1);
+        let end = zero.start_time + Duration::days(30);
+        assert_eq!(zero.occurrences_between(zero.start_time, end, 10).unwrap().len(), 3);
+
+        let early = &cal.find_events_by_title("Early until")[0];
+        let occurrences = early.occurrences_between(early.start_time, end, 10).unwrap();
+        assert_eq!(occurrences, vec![early.start_time]);
+    }
+
     #[test]
     fn test_parse_rrule_value_covers_yearly_and_numeric_errors() {
         let tz = crate::timezone::parse_timezone("UTC").unwrap();
@@ -627,6 +2729,30 @@ mod tests {
         assert_eq!(dt.month(), 11);
     }
 
+    #[test]
+    fn test_ics_import_minute_precision_datetime() {
+        let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:minutes@example.com
+SUMMARY:Coffee
+DTSTART:20251027T1030
+DTEND:20251027T1100Z
+END:VEVENT
+END:VCALENDAR
+";
+
+        let cal = Calendar::from_ics_string(ics).unwrap();
+        let event = &cal.events[0];
+        assert_eq!((event.start_time.hour(), event.start_time.minute()), (10, 30));
+        assert_eq!(event.start_time.second(), 0);
+        assert_eq!(event.end_time.minute(), 0);
+
+        // Fourteen characters is neither form
+        let tz = crate::timezone::parse_timezone("UTC").unwrap();
+        assert!(parse_ical_datetime_value("20251027T10301", tz).is_err());
+    }
+
     #[test]
     fn test_parse_ical_datetime_value_invalid_short_string() {
         let tz = crate::timezone::parse_timezone("UTC").unwrap();
@@ -665,6 +2791,45 @@ mod tests {
         std::fs::remove_file(&path).ok();
     }
 
+    #[test]
+    fn test_export_to_ics_atomic_replaces_file_without_leftovers() {
+        let mut cal = Calendar::new("Atomic");
+        cal.add_event(
+            Event::builder()
+                .title("Disk Event")
+                .start("2025-11-01 12:00:00", "UTC")
+                .duration_hours(1)
+                .build()
+                .unwrap(),
+        );
+
+        let dir = std::env::temp_dir().join(format!("eventix_atomic_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&dir).unwrap();
+        let path = dir.join("calendar.ics");
+        std::fs::write(&path, "stale partial content").unwrap();
+
+        cal.export_to_ics_atomic(&path).unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(!written.contains("stale"));
+        assert!(written.starts_with("BEGIN:VCALENDAR"));
+        assert!(written.trim_end().ends_with("END:VCALENDAR"));
+        assert_eq!(Calendar::import_from_ics(&path).unwrap().events[0].title, "Disk Event");
+
+        let entries: Vec<_> =
+            std::fs::read_dir(&dir).unwrap().map(|e| e.unwrap().file_name()).collect();
+        assert_eq!(entries, vec![std::ffi::OsString::from("calendar.ics")]);
+
+        // A missing directory fails without creating anything
+        let missing = dir.join("missing").join("calendar.ics");
+        let err = cal.export_to_ics_atomic(&missing).unwrap_err();
+        assert!(
+            matches!(err, EventixError::IcsError(message) if message.contains("Failed to write ICS file"))
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn test_import_from_ics_missing_file_errors() {
         let path =
@@ -694,6 +2859,56 @@ END:VCALENDAR";
         assert_eq!(cal.events[0].title, "Good");
     }
 
+    #[test]
+    fn test_from_ics_string_with_report_collects_warnings() {
+        // The second event lacks SUMMARY (one warning) and DTSTART (skipped, a second)
+        let ics = "\
+BEGIN:VCALENDAR
+BEGIN:VEVENT
+SUMMARY:Good
+DTSTART:20251101T100000Z
+DTEND:20251101T110000Z
+END:VEVENT
+BEGIN:VEVENT
+UID:broken@example.com
+END:VEVENT
+END:VCALENDAR";
+
+        let (cal, warnings) = Calendar::from_ics_string_with_report(ics).unwrap();
+        assert_eq!(cal.event_count(), 1);
+        assert_eq!(cal.events[0].title, "Good");
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("missing SUMMARY"));
+        assert!(warnings[1].starts_with("Failed to parse event"));
+    }
+
+    #[test]
+    fn test_import_missing_summary_uses_placeholder_and_warns() {
+        let ics = "\
+BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:blocked-1
+DTSTART:20251101T100000Z
+DTEND:20251101T110000Z
+END:VEVENT
+END:VCALENDAR";
+
+        let (cal, warnings) =
+            Calendar::from_ics_string_with_options(ics, &IcsImportOptions::default()).unwrap();
+        assert_eq!(cal.event_count(), 1);
+        assert_eq!(cal.events[0].title, "(No title)");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("blocked-1"));
+        assert!(warnings[0].contains("missing SUMMARY"));
+
+        let options = IcsImportOptions {
+            missing_summary_placeholder: "Busy".to_string(),
+            ..Default::default()
+        };
+        let (cal, _) = Calendar::from_ics_string_with_options(ics, &options).unwrap();
+        assert_eq!(cal.events[0].title, "Busy");
+    }
+
     #[test]
     fn test_parse_rrule_secondly_from_ics_import() {
         let ics = "\
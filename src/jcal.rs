@@ -0,0 +1,490 @@
+//! jCal (RFC 7265, JSON iCalendar) import and export
+//!
+//! jCal is a lossless JSON mapping of iCalendar: every component becomes a
+//! `[name, [properties...], [components...]]` array and every property a
+//! `[name, {params}, type, value]` array. The exporter reuses the ICS event
+//! model, so TZID parameters, alarms and recurrence rules match
+//! [`Calendar::to_ics_string()`] exactly, and the importer feeds the same
+//! event conversion used by [`Calendar::from_ics_string()`].
+
+use crate::calendar::Calendar;
+use crate::error::{EventixError, Result};
+use crate::ics::{import_ical_events, IcsImportOptions};
+use chrono::Duration;
+use icalendar::{Alarm, CalendarComponent, Component, Event as IEvent, EventLike, Property};
+use serde_json::{json, Map, Value};
+
+impl Calendar {
+    /// Convert this calendar to a jCal (RFC 7265) JSON string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{Calendar, Event};
+    ///
+    /// let mut cal = Calendar::new("Team");
+    /// cal.add_event(
+    ///     Event::builder()
+    ///         .title("Standup")
+    ///         .start("2025-11-03 09:00:00", "America/New_York")
+    ///         .duration_minutes(15)
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let jcal = cal.to_jcal().unwrap();
+    /// assert!(jcal.starts_with("[\"vcalendar\""));
+    /// ```
+    pub fn to_jcal(&self) -> Result<String> {
+        let ical = self.to_ical()?;
+
+        let properties: Vec<Value> = ical.properties.iter().map(property_to_jcal).collect();
+        let components: Vec<Value> = ical
+            .components
+            .iter()
+            .filter_map(|component| match component {
+                CalendarComponent::Event(event) => Some(component_to_jcal(event)),
+                CalendarComponent::Todo(todo) => Some(component_to_jcal(todo)),
+                CalendarComponent::Venue(venue) => Some(component_to_jcal(venue)),
+                CalendarComponent::Other(other) => Some(component_to_jcal(other)),
+                _ => None,
+            })
+            .collect();
+
+        serde_json::to_string(&json!(["vcalendar", properties, components]))
+            .map_err(|e| EventixError::Other(format!("JSON serialization error: {}", e)))
+    }
+
+    /// Parse a calendar from a jCal (RFC 7265) JSON string
+    ///
+    /// Properties are converted back to their iCalendar form (including
+    /// parameters such as `TZID`) and each `vevent` goes through the same
+    /// conversion as ICS import. Events that cannot be parsed are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::Calendar;
+    ///
+    /// let jcal = r#"["vcalendar", [], [["vevent", [
+    ///     ["summary", {}, "text", "Standup"],
+    ///     ["dtstart", {"tzid": "Asia/Tokyo"}, "date-time", "2025-11-03T09:00:00"],
+    ///     ["dtend", {"tzid": "Asia/Tokyo"}, "date-time", "2025-11-03T09:15:00"]
+    /// ], []]]]"#;
+    ///
+    /// let cal = Calendar::from_jcal(jcal).unwrap();
+    /// assert_eq!(cal.events[0].title, "Standup");
+    /// assert_eq!(cal.events[0].timezone.name(), "Asia/Tokyo");
+    /// ```
+    pub fn from_jcal(json: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| EventixError::IcsError(format!("Failed to parse jCal: {}", e)))?;
+
+        let (name, properties, components) = split_component(&value)?;
+        if name != "vcalendar" {
+            return Err(EventixError::IcsError(format!(
+                "Expected a vcalendar component, found '{}'",
+                name
+            )));
+        }
+
+        let mut calendar = Calendar::new("Imported Calendar");
+        for property in properties {
+            let property = jcal_to_property(property)?;
+            match property.key() {
+                "NAME" | "X-WR-CALNAME" => calendar.name = property.value().to_string(),
+                "DESCRIPTION" | "X-WR-CALDESC" => {
+                    calendar.description = Some(property.value().to_string())
+                }
+                "URL" => calendar.url = Some(property.value().to_string()),
+                "SOURCE" => calendar.source = Some(property.value().to_string()),
+                _ => {}
+            }
+        }
+
+        let options = IcsImportOptions::default();
+        // Warnings are discarded, matching `from_ics_string`
+        let mut warnings = Vec::new();
+        let mut ical_events = Vec::new();
+        for component in components {
+            let (name, properties, subcomponents) = split_component(component)?;
+            if name != "vevent" {
+                continue;
+            }
+
+            let mut ical_event = IEvent::new();
+            for property in properties {
+                append_jcal_property(&mut ical_event, jcal_to_property(property)?);
+            }
+            for subcomponent in subcomponents {
+                let (name, properties, _) = split_component(subcomponent)?;
+                if name != "valarm" {
+                    continue;
+                }
+                // Alarms can only be created through a typed constructor; the
+                // jCal properties then replace its placeholder ACTION/TRIGGER.
+                let mut alarm = Alarm::display("", Duration::zero());
+                for property in properties {
+                    alarm.append_property(jcal_to_property(property)?);
+                }
+                ical_event.alarm(alarm.done());
+            }
+
+            ical_events.push(ical_event);
+        }
+
+        let ical_events = ical_events.into_iter().map(|e| (e, Vec::new()));
+        import_ical_events(&mut calendar, ical_events, &options, &mut warnings);
+        Ok(calendar)
+    }
+}
+
+/// Properties the iCalendar parser stores as repeatable multi-properties
+const MULTI_PROPERTIES: [&str; 8] =
+    ["ATTACH", "ATTENDEE", "CATEGORIES", "COMMENT", "CONTACT", "EXDATE", "RDATE", "RESOURCES"];
+
+/// Append a converted property the same way ICS import stores it
+///
+/// `X-` properties are kept as multi-properties so repeated names survive.
+fn append_jcal_property(ical_event: &mut IEvent, property: Property) {
+    if MULTI_PROPERTIES.contains(&property.key()) || property.key().starts_with("X-") {
+        ical_event.append_multi_property(property);
+    } else {
+        ical_event.append_property(property);
+    }
+}
+
+/// Split a jCal component into its name, properties, and sub-components
+fn split_component(value: &Value) -> Result<(&str, &[Value], &[Value])> {
+    let invalid = || EventixError::IcsError(format!("Invalid jCal component: {}", value));
+    match value.as_array().map(Vec::as_slice) {
+        Some([name, properties, components]) => Ok((
+            name.as_str().ok_or_else(invalid)?,
+            properties.as_array().ok_or_else(invalid)?,
+            components.as_array().ok_or_else(invalid)?,
+        )),
+        _ => Err(invalid()),
+    }
+}
+
+/// Convert a jCal `[name, {params}, type, value...]` array to an iCalendar property
+fn jcal_to_property(value: &Value) -> Result<Property> {
+    let invalid = || EventixError::IcsError(format!("Invalid jCal property: {}", value));
+    let Some([name, params, value_type, values @ ..]) = value.as_array().map(Vec::as_slice) else {
+        return Err(invalid());
+    };
+    let name = name.as_str().ok_or_else(invalid)?.to_uppercase();
+    let value_type = value_type.as_str().ok_or_else(invalid)?;
+    let params = params.as_object().ok_or_else(invalid)?;
+
+    // Multi-valued properties list each value as a further array element;
+    // structured values (GEO, REQUEST-STATUS) are arrays of components
+    // joined with `;` as in the text form
+    let text = values
+        .iter()
+        .map(|v| match (value_type, v) {
+            ("date" | "date-time", _) => v.as_str().map(|s| s.replace(['-', ':'], "")),
+            ("recur", _) => v.as_object().map(recur_from_jcal),
+            (_, Value::Array(components)) => components
+                .iter()
+                .map(|component| match component {
+                    Value::Array(items) => items
+                        .iter()
+                        .map(jcal_scalar_text)
+                        .collect::<Option<Vec<_>>>()
+                        .map(|items| items.join(",")),
+                    other => jcal_scalar_text(other),
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(|components| components.join(";")),
+            _ => jcal_scalar_text(v),
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(invalid)?
+        .join(",");
+
+    let mut property = Property::new(name, text);
+    for (key, param) in params {
+        let param = match param {
+            Value::Array(items) => {
+                items.iter().filter_map(Value::as_str).collect::<Vec<_>>().join(",")
+            }
+            other => other.as_str().ok_or_else(invalid)?.to_string(),
+        };
+        property.add_parameter(&key.to_uppercase(), &param);
+    }
+    if value_type == "date" {
+        property.add_parameter("VALUE", "DATE");
+    } else if value_type == "binary" {
+        property.add_parameter("VALUE", "BINARY");
+    }
+
+    Ok(property)
+}
+
+/// Text form of a jCal string, number or boolean value
+fn jcal_scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string().to_uppercase()),
+        _ => None,
+    }
+}
+
+/// Convert a jCal recur object back to an RRULE value, with FREQ first
+fn recur_from_jcal(recur: &Map<String, Value>) -> String {
+    let mut parts = Vec::new();
+    if let Some(freq) = recur.get("freq").and_then(Value::as_str) {
+        parts.push(format!("FREQ={}", freq));
+    }
+    for (key, value) in recur {
+        let value = match (key.as_str(), value) {
+            ("freq", _) => continue,
+            ("until", Value::String(s)) => s.replace(['-', ':'], ""),
+            (_, Value::Array(items)) => items
+                .iter()
+                .map(|v| v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string()))
+                .collect::<Vec<_>>()
+                .join(","),
+            (_, Value::String(s)) => s.clone(),
+            (_, other) => other.to_string(),
+        };
+        parts.push(format!("{}={}", key.to_uppercase(), value));
+    }
+    parts.join(";")
+}
+
+/// Convert an iCalendar component (and its sub-components) to a jCal array
+fn component_to_jcal<C: Component>(component: &C) -> Value {
+    let properties: Vec<Value> = component
+        .properties()
+        .values()
+        .chain(component.multi_properties().values().flatten())
+        .map(property_to_jcal)
+        .collect();
+    let components: Vec<Value> = component.components().iter().map(component_to_jcal).collect();
+
+    json!([component.component_kind().to_lowercase(), properties, components])
+}
+
+/// Convert a single property to a jCal `[name, {params}, type, value]` array
+fn property_to_jcal(property: &Property) -> Value {
+    let name = property.key().to_lowercase();
+    let value = property.value();
+
+    // VALUE is expressed through the jCal type slot, not as a parameter
+    let mut params = Map::new();
+    for (key, param) in property.params() {
+        if key != "VALUE" {
+            params.insert(key.to_lowercase(), Value::String(param.value().to_string()));
+        }
+    }
+    let is_date = property.params().get("VALUE").is_some_and(|p| p.value() == "DATE");
+
+    let (value_type, value) = match name.as_str() {
+        "dtstart" | "dtend" | "exdate" | "rdate" | "recurrence-id" | "dtstamp" | "created"
+        | "last-modified" => {
+            if is_date {
+                ("date", Value::String(jcal_date(value)))
+            } else {
+                ("date-time", Value::String(jcal_date_time(value)))
+            }
+        }
+        "sequence" | "priority" => match value.parse::<i64>() {
+            Ok(n) => ("integer", json!(n)),
+            Err(_) => ("text", Value::String(value.to_string())),
+        },
+        "rrule" => ("recur", recur_to_jcal(value)),
+        "trigger" => ("duration", Value::String(value.to_string())),
+        "attendee" | "organizer" => ("cal-address", Value::String(value.to_string())),
+        "url" | "source" => ("uri", Value::String(value.to_string())),
+        "attach" if property.params().get("VALUE").is_some_and(|p| p.value() == "BINARY") => {
+            ("binary", Value::String(value.to_string()))
+        }
+        "attach" => ("uri", Value::String(value.to_string())),
+        _ => ("text", Value::String(value.to_string())),
+    };
+
+    json!([name, params, value_type, value])
+}
+
+/// Convert an RRULE value (`FREQ=WEEKLY;BYDAY=MO,WE`) to a jCal recur object
+fn recur_to_jcal(rrule: &str) -> Value {
+    let mut recur = Map::new();
+    for part in rrule.split(';') {
+        let Some((key, value)) = part.split_once('=') else {
+            continue;
+        };
+        let key = key.to_lowercase();
+        let value = match key.as_str() {
+            "count" | "interval" => value
+                .parse::<i64>()
+                .map(|n| json!(n))
+                .unwrap_or_else(|_| Value::String(value.to_string())),
+            "until" if value.len() == 8 => Value::String(jcal_date(value)),
+            "until" => Value::String(jcal_date_time(value)),
+            _ if value.contains(',') => {
+                Value::Array(value.split(',').map(|v| Value::String(v.to_string())).collect())
+            }
+            _ => Value::String(value.to_string()),
+        };
+        recur.insert(key, value);
+    }
+    Value::Object(recur)
+}
+
+/// Convert an iCalendar DATE (`20251027`) to jCal form (`2025-10-27`)
+fn jcal_date(value: &str) -> String {
+    match (value.get(0..4), value.get(4..6), value.get(6..8)) {
+        (Some(y), Some(m), Some(d)) => format!("{}-{}-{}", y, m, d),
+        _ => value.to_string(),
+    }
+}
+
+/// Convert an iCalendar DATE-TIME (`20251027T100000Z`) to jCal form
+/// (`2025-10-27T10:00:00Z`), keeping the UTC marker when present
+fn jcal_date_time(value: &str) -> String {
+    let Some((date, time)) = value.split_once('T') else {
+        return jcal_date(value);
+    };
+    let (time, utc) = match time.strip_suffix('Z') {
+        Some(time) => (time, "Z"),
+        None => (time, ""),
+    };
+    match (time.get(0..2), time.get(2..4), time.get(4..6)) {
+        (Some(h), Some(m), Some(s)) => format!("{}T{}:{}:{}{}", jcal_date(date), h, m, s, utc),
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::{Event, Recurrence};
+
+    #[test]
+    fn test_to_jcal_structure() {
+        let mut cal = Calendar::new("Work");
+        cal.add_event(
+            Event::builder()
+                .title("Standup")
+                .start("2025-10-27 10:00:00", "America/New_York")
+                .duration_minutes(15)
+                .recurrence(Recurrence::daily().count(5))
+                .build()
+                .unwrap(),
+        );
+
+        let value: Value = serde_json::from_str(&cal.to_jcal().unwrap()).unwrap();
+        assert_eq!(value[0], "vcalendar");
+        assert!(value[1].as_array().unwrap().iter().any(|p| p[0] == "version" && p[3] == "2.0"));
+
+        let vevent = &value[2][0];
+        assert_eq!(vevent[0], "vevent");
+        let props = vevent[1].as_array().unwrap();
+        let prop = |name: &str| props.iter().find(|p| p[0] == name).unwrap();
+
+        assert_eq!(prop("summary")[3], "Standup");
+        assert_eq!(prop("dtstart")[1]["tzid"], "America/New_York");
+        assert_eq!(prop("dtstart")[2], "date-time");
+        assert_eq!(prop("dtstart")[3], "2025-10-27T10:00:00");
+        assert_eq!(prop("sequence")[3], 0);
+        assert_eq!(prop("rrule")[3]["freq"], "DAILY");
+        assert_eq!(prop("rrule")[3]["count"], 5);
+    }
+
+    #[test]
+    fn test_jcal_round_trip_preserves_events() {
+        let mut cal = Calendar::new("Work");
+        cal.description = Some("Team calendar".to_string());
+        cal.add_event(
+            Event::builder()
+                .title("Planning, Q4")
+                .start("2025-10-27 10:00:00", "America/New_York")
+                .duration_hours(1)
+                .recurrence(Recurrence::weekly().count(3))
+                .exception_date(
+                    crate::timezone::parse_datetime_with_tz(
+                        "2025-11-03 10:00:00",
+                        crate::timezone::parse_timezone("America/New_York").unwrap(),
+                    )
+                    .unwrap(),
+                )
+                .category("work")
+                .reminder(crate::Reminder::RelativeToStart(chrono::Duration::minutes(-10)))
+                .build()
+                .unwrap(),
+        );
+        cal.add_event(
+            Event::builder()
+                .title("Deploy")
+                .start("2025-10-28 16:00:00", "UTC")
+                .duration_minutes(30)
+                .build()
+                .unwrap(),
+        );
+
+        let restored = Calendar::from_jcal(&cal.to_jcal().unwrap()).unwrap();
+        assert_eq!(restored.name, "Work");
+        assert_eq!(restored.description.as_deref(), Some("Team calendar"));
+        assert_eq!(restored.event_count(), 2);
+
+        for (original, imported) in cal.events.iter().zip(&restored.events) {
+            assert_eq!(imported.title, original.title);
+            assert_eq!(imported.start_time, original.start_time);
+            assert_eq!(imported.end_time, original.end_time);
+            assert_eq!(imported.timezone, original.timezone);
+            assert_eq!(
+                imported.recurrence.as_ref().map(|r| r.get_count()),
+                original.recurrence.as_ref().map(|r| r.get_count())
+            );
+            assert_eq!(imported.exdates, original.exdates);
+            assert_eq!(imported.categories, original.categories);
+            assert_eq!(imported.reminders, original.reminders);
+        }
+    }
+
+    #[test]
+    fn test_from_jcal_rejects_malformed_input() {
+        assert!(matches!(Calendar::from_jcal("{"), Err(EventixError::IcsError(_))));
+        assert!(matches!(
+            Calendar::from_jcal(r#"["vevent", [], []]"#),
+            Err(EventixError::IcsError(_))
+        ));
+        assert!(matches!(
+            Calendar::from_jcal(r#"["vcalendar", [["version"]], []]"#),
+            Err(EventixError::IcsError(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_jcal_accepts_structured_values() {
+        let jcal = r#"["vcalendar", [], [["vevent", [
+            ["uid", {}, "text", "geo@example.com"],
+            ["summary", {}, "text", "Site visit"],
+            ["dtstart", {}, "date-time", "2025-11-03T09:00:00Z"],
+            ["dtend", {}, "date-time", "2025-11-03T10:00:00Z"],
+            ["geo", {}, "float", [37.38, -122.08]],
+            ["request-status", {}, "text", ["2.0", "Success"]]
+        ], []]]]"#;
+
+        let cal = Calendar::from_jcal(jcal).unwrap();
+        assert_eq!(cal.event_count(), 1);
+        assert_eq!(cal.events[0].title, "Site visit");
+
+        let geo = jcal_to_property(&json!(["geo", {}, "float", [37.38, -122.08]])).unwrap();
+        assert_eq!(geo.value(), "37.38;-122.08");
+        let status =
+            jcal_to_property(&json!(["request-status", {}, "text", ["3.7", ["a", "b"]]])).unwrap();
+        assert_eq!(status.value(), "3.7;a,b");
+    }
+
+    #[test]
+    fn test_jcal_date_time_conversion() {
+        assert_eq!(jcal_date_time("20251027T100000"), "2025-10-27T10:00:00");
+        assert_eq!(jcal_date_time("20251027T100000Z"), "2025-10-27T10:00:00Z");
+        assert_eq!(jcal_date_time("20251027"), "2025-10-27");
+    }
+}
@@ -51,7 +51,8 @@
 //!     .unwrap();
 //!
 //! cal.add_event(event);
-//! cal.export_to_ics("schedule.ics").unwrap();
+//! let path = std::env::temp_dir().join("schedule.ics");
+//! cal.export_to_ics(&path).unwrap();
 //!
 //! // Generates: DTSTART;TZID=America/New_York:20251027T100000
 //! // Compatible with Google Calendar, Outlook, and Apple Calendar
@@ -101,6 +102,7 @@
 //! ## Modules
 //!
 //! - [`calendar`] - Calendar container for managing collections of events
+//! - [`clock`] - Injectable time sources for "now"-based queries
 //! - [`event`] - Event types and builder API
 //! - [`gap_validation`] - Schedule analysis, gap detection, and conflict resolution (unique feature)
 //! - [`ics`] - ICS (iCalendar) import/export with TZID support
@@ -119,18 +121,24 @@
 //! - `gap_validation.rs` - Schedule analysis and gap detection features
 
 pub mod calendar;
+pub mod clock;
 pub mod event;
 pub mod gap_validation;
 pub mod ics;
+pub mod jcal;
 pub mod recurrence;
 pub mod timezone;
 pub mod views;
 
 mod error;
 
-pub use calendar::Calendar;
+pub use calendar::{Calendar, CalendarBuilder};
+pub use clock::{Clock, FixedClock, SystemClock};
 pub use error::{EventixError, Result};
-pub use event::{Event, EventBuilder, EventStatus};
+pub use event::{
+    Classification, Event, EventBuilder, EventOverride, EventStatus, Reminder, SnapMode,
+    Transparency,
+};
 pub use recurrence::{OccurrenceIterator, Recurrence};
 pub use views::{DayIterator, DayView, OwnedEventOccurrence, WeekIterator, WeekView};
 
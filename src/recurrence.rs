@@ -1,19 +1,23 @@
 //! Recurrence rules and patterns for repeating events
 
-use crate::error::Result;
+use crate::error::{EventixError, Result};
 use crate::timezone::resolve_local;
 use chrono::{DateTime, Datelike};
 use chrono_tz::Tz;
 use rrule::Frequency;
 
 /// Recurrence pattern for events
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Recurrence {
     frequency: Frequency,
     interval: u16,
     count: Option<u32>,
     until: Option<DateTime<Tz>>,
     by_weekday: Option<Vec<rrule::Weekday>>,
+    week_start: rrule::Weekday,
+    /// RRULE parts the built-in generator does not handle (e.g.
+    /// `BYDAY=MO;BYSETPOS=1`); when set, generation delegates to `rrule`
+    extra_rule_parts: Option<String>,
 }
 
 impl Recurrence {
@@ -32,9 +36,91 @@ impl Recurrence {
             count: None,
             until: None,
             by_weekday: None,
+            week_start: rrule::Weekday::Mon,
+            extra_rule_parts: None,
         }
     }
 
+    /// Parse an RRULE value, with full RFC 5545 coverage via the `rrule` crate
+    ///
+    /// Rules made only of `FREQ`, `INTERVAL`, `COUNT`, `UNTIL`, `WKST`, and
+    /// plain `BYDAY` weekdays use the built-in generator, exactly like the
+    /// builder methods. Anything else (`BYSETPOS`, `BYMONTHDAY`, `BYWEEKNO`,
+    /// ordinal `BYDAY` such as `-1FR`, ...) is kept verbatim and expanded by
+    /// `rrule`. An optional `RRULE:` prefix is accepted. `dtstart` is only
+    /// used to validate the rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EventixError::RecurrenceError`] if the rule is malformed or
+    /// invalid for `dtstart`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::{timezone, Recurrence};
+    ///
+    /// let tz = timezone::parse_timezone("UTC").unwrap();
+    /// let start = timezone::parse_datetime_with_tz("2025-01-06 09:00:00", tz).unwrap();
+    ///
+    /// // Last Friday of every month
+    /// let recurrence = Recurrence::from_rrule_str(start, "FREQ=MONTHLY;BYDAY=-1FR;COUNT=2").unwrap();
+    /// let dates: Vec<_> = recurrence.occurrences(start).map(|d| d.format("%Y-%m-%d").to_string()).collect();
+    /// assert_eq!(dates, vec!["2025-01-31", "2025-02-28"]);
+    /// ```
+    pub fn from_rrule_str(dtstart: DateTime<Tz>, rrule: &str) -> Result<Self> {
+        let value = rrule.trim();
+        let value = value.strip_prefix("RRULE:").unwrap_or(value);
+        let invalid = |e: rrule::RRuleError| {
+            EventixError::RecurrenceError(format!("Invalid RRULE '{}': {}", value, e))
+        };
+
+        let parsed: rrule::RRule<rrule::Unvalidated> = value.parse().map_err(invalid)?;
+        parsed
+            .clone()
+            .validate(dtstart.with_timezone(&rrule::Tz::Tz(dtstart.timezone())))
+            .map_err(invalid)?;
+
+        let mut by_day = None;
+        let mut extra = Vec::new();
+        for part in value.split(';').filter(|p| !p.is_empty()) {
+            let key = part.split_once('=').map_or(part, |(k, _)| k).to_ascii_uppercase();
+            match key.as_str() {
+                "FREQ" | "INTERVAL" | "COUNT" | "UNTIL" | "WKST" => {}
+                "BYDAY" => by_day = Some(part),
+                _ => extra.push(part),
+            }
+        }
+
+        // Plain weekdays are native unless another part needs `rrule` anyway
+        let weekdays = parsed
+            .get_by_weekday()
+            .iter()
+            .map(|nwd| match nwd {
+                rrule::NWeekday::Every(wd) => Some(*wd),
+                rrule::NWeekday::Nth(..) => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        let by_weekday = match (by_day, weekdays) {
+            (Some(_), Some(weekdays)) if extra.is_empty() => Some(weekdays),
+            (Some(part), _) => {
+                extra.push(part);
+                None
+            }
+            (None, _) => None,
+        };
+
+        Ok(Self {
+            frequency: parsed.get_freq(),
+            interval: parsed.get_interval(),
+            count: parsed.get_count(),
+            until: parsed.get_until().map(|u| u.with_timezone(&dtstart.timezone())),
+            by_weekday,
+            week_start: parsed.get_week_start(),
+            extra_rule_parts: (!extra.is_empty()).then(|| extra.join(";")),
+        })
+    }
+
     /// Create a daily recurrence pattern
     ///
     /// # Examples
@@ -140,7 +226,9 @@ impl Recurrence {
 
     /// Set the interval between recurrences
     ///
-    /// An interval of 0 is normalized to 1 (the RFC 5545 default).
+    /// An interval of 0 is stored as given so that
+    /// [`EventBuilder::build`](crate::EventBuilder::build) can reject it; the
+    /// occurrence iterator treats it as 1 (the RFC 5545 default).
     ///
     /// # Examples
     ///
@@ -151,11 +239,7 @@ impl Recurrence {
     /// let biweekly = Recurrence::weekly().interval(2).count(10);
     /// ```
     pub fn interval(mut self, interval: u16) -> Self {
-        self.interval = if interval == 0 {
-            1
-        } else {
-            interval
-        };
+        self.interval = interval;
         self
     }
 
@@ -175,6 +259,10 @@ impl Recurrence {
 
     /// Set the end date for recurrence
     ///
+    /// `until` is inclusive, as with RFC 5545 `UNTIL`: an occurrence falling
+    /// exactly on the `until` instant is generated, and anything later is
+    /// not. The same rule applies to every frequency and weekday expansion.
+    ///
     /// # Examples
     ///
     /// ```
@@ -234,6 +322,28 @@ impl Recurrence {
         self
     }
 
+    /// Set the day each week period starts on (RFC 5545 `WKST`)
+    ///
+    /// Only affects weekly recurrences with weekdays and `interval > 1`,
+    /// where it decides which listed weekdays share a period. Defaults to
+    /// Monday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::Recurrence;
+    /// use rrule::Weekday;
+    ///
+    /// let biweekly = Recurrence::weekly()
+    ///     .interval(2)
+    ///     .weekdays(vec![Weekday::Mon, Weekday::Sun])
+    ///     .week_start(Weekday::Sun);
+    /// ```
+    pub fn week_start(mut self, week_start: rrule::Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
     /// Get the frequency of this recurrence
     pub fn frequency(&self) -> Frequency {
         self.frequency
@@ -259,6 +369,96 @@ impl Recurrence {
         self.by_weekday.as_deref()
     }
 
+    /// Get the day week periods start on
+    pub fn get_week_start(&self) -> rrule::Weekday {
+        self.week_start
+    }
+
+    /// Copy of this recurrence limited to its first `count` occurrences
+    ///
+    /// Any `until` bound is dropped so the result never carries both
+    /// COUNT and UNTIL.
+    pub(crate) fn truncated(&self, count: u32) -> Self {
+        Self {
+            count: Some(count),
+            until: None,
+            ..self.clone()
+        }
+    }
+
+    /// Copy of this recurrence ending at `until`, dropping any `count`
+    pub(crate) fn ending_at(&self, until: DateTime<Tz>) -> Self {
+        Self {
+            count: None,
+            until: Some(until),
+            ..self.clone()
+        }
+    }
+
+    /// Copy of this recurrence for a series resumed after `skipped`
+    /// occurrences, reducing any `count` accordingly
+    pub(crate) fn resumed_after(&self, skipped: u32) -> Self {
+        Self {
+            count: self.count.map(|c| c.saturating_sub(skipped)),
+            ..self.clone()
+        }
+    }
+
+    /// Describe this recurrence in plain English
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventix::Recurrence;
+    /// use rrule::Weekday;
+    ///
+    /// assert_eq!(Recurrence::daily().count(20).describe(), "Daily, 20 times");
+    /// assert_eq!(
+    ///     Recurrence::weekly().interval(2).weekdays(vec![Weekday::Mon, Weekday::Fri]).describe(),
+    ///     "Every 2 weeks on Mon, Fri"
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        let unit = match self.frequency {
+            Frequency::Secondly => "second",
+            Frequency::Minutely => "minute",
+            Frequency::Hourly => "hour",
+            Frequency::Daily => "day",
+            Frequency::Weekly => "week",
+            Frequency::Monthly => "month",
+            Frequency::Yearly => "year",
+        };
+
+        let mut description = match (self.interval.max(1), self.frequency) {
+            (1, Frequency::Daily) => "Daily".to_string(),
+            (1, Frequency::Hourly) => "Hourly".to_string(),
+            (1, Frequency::Weekly) => "Weekly".to_string(),
+            (1, Frequency::Monthly) => "Monthly".to_string(),
+            (1, Frequency::Yearly) => "Yearly".to_string(),
+            (1, _) => format!("Every {}", unit),
+            (n, _) => format!("Every {} {}s", n, unit),
+        };
+
+        if let Some(ref weekdays) = self.by_weekday {
+            let days: Vec<String> = weekdays.iter().map(|wd| format!("{:?}", wd)).collect();
+            description.push_str(&format!(" on {}", days.join(", ")));
+        }
+
+        if let Some(count) = self.count {
+            if count == 1 {
+                description.push_str(", once");
+            } else {
+                description.push_str(&format!(", {} times", count));
+            }
+        }
+
+        if let Some(until) = self.until {
+            description.push_str(&format!(", until {}", until.format("%Y-%m-%d")));
+        }
+
+        description
+    }
+
     /// Build an RRule string for this recurrence
     pub fn to_rrule_string(&self, dtstart: DateTime<Tz>) -> Result<String> {
         let mut rrule_str = format!("FREQ={:?}", self.frequency).to_uppercase();
@@ -286,9 +486,26 @@ impl Recurrence {
             }
         }
 
+        if self.week_start != rrule::Weekday::Mon {
+            rrule_str.push_str(&format!(";WKST={}", weekday_code(self.week_start)));
+        }
+
+        if let Some(ref extra) = self.extra_rule_parts {
+            rrule_str.push(';');
+            rrule_str.push_str(extra);
+        }
+
         Ok(format!("DTSTART:{}\nRRULE:{}", dtstart.format("%Y%m%dT%H%M%S"), rrule_str))
     }
 
+    /// Build the equivalent `rrule` set anchored at `dtstart`
+    fn to_rrule_set(&self, dtstart: DateTime<Tz>) -> Option<rrule::RRuleSet> {
+        let rrule_str = self.to_rrule_string(dtstart).ok()?;
+        let value = rrule_str.lines().find_map(|l| l.strip_prefix("RRULE:"))?;
+        let rule: rrule::RRule<rrule::Unvalidated> = value.parse().ok()?;
+        rule.build(dtstart.with_timezone(&rrule::Tz::Tz(dtstart.timezone()))).ok()
+    }
+
     /// Generate occurrences for this recurrence pattern (eager, allocates Vec)
     /// until the recurrence naturally exhausts via `count`, `until`, or
     /// iterator termination.
@@ -386,6 +603,11 @@ impl Recurrence {
 ///   valid day (e.g. Jan 31 → Feb 28)
 /// - [`Frequency::Yearly`]  — adds `interval` years, clamping for leap days
 ///   (e.g. Feb 29 → Feb 28 in non-leap years)
+///
+/// Calendar-aligned steps are computed on the local date in the event's own
+/// timezone and always land on `intended_time`.  When that wall-clock time
+/// is ambiguous (fall-back), the earliest instant is chosen; when it falls
+/// in a spring-forward gap, the pre-gap offset is applied.
 fn advance_by_frequency(
     current: DateTime<Tz>,
     frequency: Frequency,
@@ -398,12 +620,14 @@ fn advance_by_frequency(
     let tz = current.timezone();
     match frequency {
         Frequency::Daily => {
-            let new_date = current.date_naive() + chrono::Days::new(interval as u64);
+            let new_date =
+                current.date_naive().checked_add_days(chrono::Days::new(interval as u64))?;
             let naive = chrono::NaiveDateTime::new(new_date, intended_time);
             resolve_local(tz, naive)
         }
         Frequency::Weekly => {
-            let new_date = current.date_naive() + chrono::Days::new(interval as u64 * 7);
+            let new_date =
+                current.date_naive().checked_add_days(chrono::Days::new(interval as u64 * 7))?;
             let naive = chrono::NaiveDateTime::new(new_date, intended_time);
             resolve_local(tz, naive)
         }
@@ -430,9 +654,26 @@ fn advance_by_frequency(
         // chrono::Duration to a DateTime<Tz> always goes through UTC,
         // so spring-forward / fall-back transitions are handled transparently
         // without any local-time lookup.
-        Frequency::Hourly => Some(current + chrono::Duration::hours(interval as i64)),
-        Frequency::Minutely => Some(current + chrono::Duration::minutes(interval as i64)),
-        Frequency::Secondly => Some(current + chrono::Duration::seconds(interval as i64)),
+        Frequency::Hourly => current.checked_add_signed(chrono::Duration::hours(interval as i64)),
+        Frequency::Minutely => {
+            current.checked_add_signed(chrono::Duration::minutes(interval as i64))
+        }
+        Frequency::Secondly => {
+            current.checked_add_signed(chrono::Duration::seconds(interval as i64))
+        }
+    }
+}
+
+/// RFC 5545 two-letter code for a weekday (e.g. `MO`)
+pub(crate) fn weekday_code(weekday: chrono::Weekday) -> &'static str {
+    match weekday {
+        chrono::Weekday::Mon => "MO",
+        chrono::Weekday::Tue => "TU",
+        chrono::Weekday::Wed => "WE",
+        chrono::Weekday::Thu => "TH",
+        chrono::Weekday::Fri => "FR",
+        chrono::Weekday::Sat => "SA",
+        chrono::Weekday::Sun => "SU",
     }
 }
 
@@ -460,11 +701,12 @@ fn clamp_day_to_month(year: i32, month: u32, day: u32) -> Option<chrono::NaiveDa
 /// weekday. Within the current week period, steps forward day-by-day.
 /// When no more matching weekdays remain in this week, jumps by
 /// `interval` weeks to reach the next week period and finds the first
-/// matching weekday there.
+/// matching weekday there. Week periods begin on `week_start` (WKST).
 fn advance_weekly_weekday(
     current: DateTime<Tz>,
     interval: u16,
     weekdays: &[chrono::Weekday],
+    week_start: chrono::Weekday,
     intended_time: chrono::NaiveTime,
 ) -> Option<DateTime<Tz>> {
     // Match the zero-interval guard in advance_by_frequency(): no further
@@ -475,9 +717,10 @@ fn advance_weekly_weekday(
 
     let tz = current.timezone();
     let date = current.date_naive();
-    let current_dow = date.weekday().num_days_from_monday(); // 0=Mon..6=Sun
+    // Position within the week period, 0 = week_start
+    let current_dow = date.weekday().days_since(week_start);
 
-    // Try remaining days in the current calendar week (Mon-Sun).
+    // Try remaining days in the current week period.
     // Only consider days strictly after the current weekday within this week.
     for day_offset in 1u64..(7 - current_dow as u64) {
         let candidate = date + chrono::Days::new(day_offset);
@@ -488,7 +731,7 @@ fn advance_weekly_weekday(
     }
 
     // No more matching weekdays this week — jump to the next week period.
-    // Find the Monday of the current week, then advance by interval weeks.
+    // Find the start of the current week period, then advance by interval weeks.
     let week_start = date - chrono::Days::new(current_dow as u64);
     let next_week_start = week_start + chrono::Days::new(interval as u64 * 7);
 
@@ -684,12 +927,24 @@ pub struct OccurrenceIterator {
     byday_next_month: u32,
     /// Whether the first BYDAY period has been expanded
     byday_first: bool,
+    /// Expansion handed to the `rrule` crate for rules built from
+    /// [`Recurrence::from_rrule_str`] that the built-in generator can't cover
+    delegate: Option<rrule::RRuleSetIter>,
 }
 
 impl OccurrenceIterator {
     /// Create a new occurrence iterator
-    fn new(recurrence: Recurrence, start: DateTime<Tz>) -> Self {
+    fn new(mut recurrence: Recurrence, start: DateTime<Tz>) -> Self {
+        recurrence.interval = recurrence.interval.max(1);
+        let mut exhausted = false;
+        let delegate = recurrence.extra_rule_parts.as_ref().and_then(|_| {
+            let set = recurrence.to_rrule_set(start);
+            // The rule was validated when parsed; an unusable start ends the series
+            exhausted = set.is_none();
+            set.map(|set| (&set).into_iter())
+        });
         Self {
+            delegate,
             byday_next_year: start.year(),
             byday_next_month: start.month(),
             byday_first: true,
@@ -698,7 +953,7 @@ impl OccurrenceIterator {
             intended_time: start.time(),
             current: start,
             count: 0,
-            exhausted: false,
+            exhausted,
         }
     }
 
@@ -840,6 +1095,14 @@ impl Iterator for OccurrenceIterator {
     type Item = DateTime<Tz>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        if let Some(ref mut delegate) = self.delegate {
+            let tz = self.current.timezone();
+            return delegate.next().map(|dt| dt.with_timezone(&tz));
+        }
+
         // Fast path: no weekday filter active
         // Avoids per-iteration frequency checks that cause regression on
         // the common no-weekday path (daily, minutely, hourly, etc.)
@@ -877,6 +1140,7 @@ impl Iterator for OccurrenceIterator {
                         result,
                         self.recurrence.interval,
                         weekdays,
+                        self.recurrence.week_start,
                         self.intended_time,
                     ) {
                         Some(next) => self.current = next,
@@ -952,7 +1216,7 @@ impl Iterator for OccurrenceIterator {
 }
 
 /// Filter for skipping certain dates (e.g., weekends, holidays)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RecurrenceFilter {
     skip_weekends: bool,
     skip_dates: Vec<DateTime<Tz>>,
@@ -979,6 +1243,11 @@ impl RecurrenceFilter {
         self
     }
 
+    /// Whether weekends are skipped
+    pub fn skips_weekends(&self) -> bool {
+        self.skip_weekends
+    }
+
     /// Check if a date should be skipped
     pub fn should_skip(&self, date: &DateTime<Tz>) -> bool {
         // Check if it's a weekend
@@ -1029,6 +1298,22 @@ mod tests {
         assert_eq!(recurrence.get_count(), Some(10));
     }
 
+    #[test]
+    fn test_recurrence_equality_compares_all_fields() {
+        let a = Recurrence::weekly().interval(2).count(10);
+        let b = Recurrence::weekly().interval(2).count(10);
+        assert_eq!(a, b);
+        assert_ne!(a, Recurrence::weekly().count(10));
+        assert_ne!(a, b.clone().week_start(rrule::Weekday::Sun));
+        assert_ne!(a, b.clone().weekdays(vec![rrule::Weekday::Tue]));
+
+        let dtstart =
+            chrono::TimeZone::with_ymd_and_hms(&chrono_tz::UTC, 2025, 11, 3, 9, 0, 0).unwrap();
+        let parsed =
+            Recurrence::from_rrule_str(dtstart, "FREQ=WEEKLY;INTERVAL=2;COUNT=10").unwrap();
+        assert_eq!(parsed, a);
+    }
+
     #[test]
     fn test_recurrence_filter_weekends() {
         let filter = RecurrenceFilter::new().skip_weekends(true);
@@ -1141,6 +1426,40 @@ mod tests {
         assert_eq!(occurrences.last().unwrap(), &end);
     }
 
+    #[test]
+    fn test_until_includes_occurrence_on_the_instant() {
+        use chrono::Duration;
+        use rrule::Weekday;
+        let tz = parse_timezone("UTC").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-01-01 09:00:00", tz).unwrap();
+
+        let recurrence = Recurrence::daily().until(start + Duration::days(3));
+        let occurrences = recurrence.generate_occurrences(start).unwrap();
+        assert_eq!(occurrences, (0..4).map(|d| start + Duration::days(d)).collect::<Vec<_>>());
+
+        // Weekday-filtered and BYDAY-expanded paths agree
+        let weekly = Recurrence::weekly()
+            .weekdays(vec![Weekday::Wed, Weekday::Sat])
+            .until(start + Duration::days(3));
+        assert_eq!(weekly.generate_occurrences(start).unwrap().len(), 2);
+        let monthly = Recurrence::monthly()
+            .weekdays(vec![Weekday::Sat])
+            .until(start + Duration::days(3));
+        assert_eq!(monthly.generate_occurrences(start).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_until_one_second_before_occurrence_excludes_it() {
+        use chrono::Duration;
+        let tz = parse_timezone("UTC").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-01-01 09:00:00", tz).unwrap();
+
+        let until = start + Duration::days(3) - Duration::seconds(1);
+        let occurrences = Recurrence::daily().until(until).generate_occurrences(start).unwrap();
+        assert_eq!(occurrences.len(), 3);
+        assert_eq!(occurrences.last(), Some(&(start + Duration::days(2))));
+    }
+
     #[test]
     fn test_lazy_iterator_size_hint_until() {
         // size_hint with `until` returns (0, None) since exact count is unknown
@@ -1218,10 +1537,10 @@ mod tests {
     }
 
     #[test]
-    fn test_zero_interval_clamped_to_one() {
-        // interval(0) is normalized to 1 (RFC 5545 default)
+    fn test_zero_interval_kept_but_iterates_like_one() {
+        // interval(0) is kept as-is for validation but iterates like 1 (RFC 5545 default)
         let recurrence = Recurrence::daily().interval(0).count(10);
-        assert_eq!(recurrence.get_interval(), 1);
+        assert_eq!(recurrence.get_interval(), 0);
 
         let tz = parse_timezone("UTC").unwrap();
         let start = crate::timezone::parse_datetime_with_tz("2025-01-01 09:00:00", tz).unwrap();
@@ -1233,13 +1552,13 @@ mod tests {
     }
 
     #[test]
-    fn test_zero_interval_weekly_weekdays_clamped_to_one() {
-        // interval(0) on weekly + weekdays is normalized to interval(1)
+    fn test_zero_interval_weekly_weekdays_iterates_like_one() {
+        // interval(0) on weekly + weekdays iterates like interval(1)
         let recurrence = Recurrence::weekly()
             .interval(0)
             .weekdays(vec![chrono::Weekday::Mon, chrono::Weekday::Wed])
             .count(5);
-        assert_eq!(recurrence.get_interval(), 1);
+        assert_eq!(recurrence.get_interval(), 0);
 
         let tz = parse_timezone("UTC").unwrap();
         // Start on a Monday
@@ -1432,6 +1751,30 @@ mod tests {
         assert_eq!(occurrences[2].hour(), 2);
     }
 
+    #[test]
+    fn test_monthly_preserves_wall_clock_on_ambiguous_day() {
+        // Monthly on the 2nd at 01:30 New York; 2025-11-02 01:30 occurs twice.
+        let recurrence = Recurrence::monthly().count(4);
+        let tz = parse_timezone("America/New_York").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-09-02 01:30:00", tz).unwrap();
+
+        let occurrences: Vec<_> = recurrence.occurrences(start).collect();
+        assert_eq!(occurrences.len(), 4);
+        for occ in &occurrences {
+            assert_eq!(occ.day(), 2);
+            assert_eq!(occ.hour(), 1);
+            assert_eq!(occ.minute(), 30);
+        }
+        // The ambiguous Nov 2 occurrence resolves to the earlier (EDT) instant.
+        assert_eq!(chrono::Offset::fix(occurrences[2].offset()).local_minus_utc(), -4 * 3600);
+        assert_eq!(
+            occurrences[2].with_timezone(&chrono::Utc).to_rfc3339(),
+            "2025-11-02T05:30:00+00:00"
+        );
+        // December is back on EST.
+        assert_eq!(chrono::Offset::fix(occurrences[3].offset()).local_minus_utc(), -5 * 3600);
+    }
+
     #[test]
     fn test_dst_fall_back_daily() {
         // US fall-back: 2025-11-02 2:00 AM → 1:00 AM in America/New_York
@@ -1453,6 +1796,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dst_fall_back_weekly_keeps_wall_clock() {
+        // Weekly steps are calendar days re-localized at the original time, not
+        // 168 elapsed hours, so the meeting stays at 10:00 across fall-back.
+        let tz = parse_timezone("America/New_York").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-10-20 10:00:00", tz).unwrap();
+
+        for recurrence in [
+            Recurrence::weekly().count(4),
+            Recurrence::weekly().weekdays(vec![chrono::Weekday::Mon]).count(4),
+        ] {
+            let occurrences: Vec<_> = recurrence.occurrences(start).collect();
+            let days: Vec<_> = occurrences.iter().map(|o| o.day()).collect();
+            assert_eq!(days, vec![20, 27, 3, 10]);
+            for occ in &occurrences {
+                assert_eq!((occ.hour(), occ.minute()), (10, 0));
+            }
+            // The UTC instant shifts by an hour once EST applies
+            assert_eq!(occurrences[1] - occurrences[0], chrono::Duration::hours(168));
+            assert_eq!(occurrences[2] - occurrences[1], chrono::Duration::hours(169));
+        }
+    }
+
     #[test]
     fn test_dst_spring_forward_eager_matches_lazy() {
         // Verify eager and lazy paths produce identical results across DST
@@ -1563,6 +1929,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_until_rrule_string_crosses_utc_date_boundary() {
+        let tz = parse_timezone("America/New_York").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-11-05 18:00:00", tz).unwrap();
+        let until = crate::timezone::parse_datetime_with_tz("2025-12-31 23:59:59", tz).unwrap();
+
+        let rrule_str = Recurrence::weekly().until(until).to_rrule_string(start).unwrap();
+
+        // EST = UTC-5, so the last local second of 2025 is early 2026 in UTC
+        assert!(rrule_str.contains("UNTIL=20260101T045959Z"), "got: {}", rrule_str);
+        assert!(!rrule_str.contains("UNTIL=20251231T235959Z"));
+
+        let last = Recurrence::weekly().until(until).occurrences(start).last().unwrap();
+        assert_eq!(
+            last,
+            crate::timezone::parse_datetime_with_tz("2025-12-31 18:00:00", tz).unwrap()
+        );
+    }
+
     #[test]
     fn test_generate_occurrences_rejects_unbounded() {
         let recurrence = Recurrence::daily(); // no count, no until
@@ -1575,7 +1960,7 @@ mod tests {
 
     #[test]
     fn test_interval_zero_rrule_string_consistent() {
-        // interval(0) is clamped to 1, so RRULE should omit INTERVAL (default=1)
+        // interval(0) is treated as 1, so RRULE should omit INTERVAL (default=1)
         let recurrence = Recurrence::daily().interval(0).count(5);
         let tz = parse_timezone("UTC").unwrap();
         let start = crate::timezone::parse_datetime_with_tz("2025-01-01 09:00:00", tz).unwrap();
@@ -2062,19 +2447,26 @@ mod tests {
             count: Some(2),
             until: None,
             by_weekday: Some(vec![]),
+            week_start: Weekday::Mon,
+            extra_rule_parts: None,
         };
         let rrule = recurrence.to_rrule_string(start).unwrap();
         assert!(!rrule.contains("BYDAY"));
 
         assert!(advance_by_frequency(start, Frequency::Daily, 0, intended).is_none());
 
+        // Stepping past the last representable date ends the series
+        let last = DateTime::<chrono::Utc>::MAX_UTC.with_timezone(&tz);
+        assert!(advance_by_frequency(last, Frequency::Weekly, 1, intended).is_none());
+        assert!(advance_by_frequency(last, Frequency::Hourly, 1, intended).is_none());
+
         let monthly = advance_by_frequency(start, Frequency::Monthly, 14, intended).unwrap();
         assert_eq!(monthly.year(), 2026);
         assert_eq!(monthly.month(), 3);
 
         assert!(clamp_day_to_month(2025, 13, 31).is_none());
-        assert!(advance_weekly_weekday(start, 0, &[Weekday::Mon], intended).is_none());
-        assert!(advance_weekly_weekday(start, 1, &[], intended).is_none());
+        assert!(advance_weekly_weekday(start, 0, &[Weekday::Mon], Weekday::Mon, intended).is_none());
+        assert!(advance_weekly_weekday(start, 1, &[], Weekday::Mon, intended).is_none());
         assert!(advance_daily_weekday(start, 0, &[Weekday::Mon], intended).is_none());
         assert!(advance_daily_weekday(start, 7, &[Weekday::Tue], intended).is_none());
         assert!(skip_subdaily_to_matching_day(start, Frequency::Hourly, 1, &[]).is_none());
@@ -2117,4 +2509,107 @@ mod tests {
         assert_eq!(no_next.next(), Some(start));
         assert!(no_next.exhausted);
     }
+
+    #[test]
+    fn test_describe_covers_interval_weekdays_and_bounds() {
+        let tz = parse_timezone("UTC").unwrap();
+        let until = crate::timezone::parse_datetime_with_tz("2025-12-31 00:00:00", tz).unwrap();
+
+        assert_eq!(Recurrence::daily().describe(), "Daily");
+        assert_eq!(Recurrence::minutely().describe(), "Every minute");
+        assert_eq!(Recurrence::monthly().interval(3).count(1).describe(), "Every 3 months, once");
+        assert_eq!(
+            Recurrence::weekly()
+                .weekdays(vec![rrule::Weekday::Tue, rrule::Weekday::Thu])
+                .until(until)
+                .describe(),
+            "Weekly on Tue, Thu, until 2025-12-31"
+        );
+    }
+
+    #[test]
+    fn test_week_start_changes_biweekly_periods() {
+        use rrule::Weekday;
+        let tz = parse_timezone("UTC").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-11-03 10:00:00", tz).unwrap();
+        let days = |wkst: Weekday| -> Vec<u32> {
+            Recurrence::weekly()
+                .interval(2)
+                .weekdays(vec![Weekday::Mon, Weekday::Sun])
+                .week_start(wkst)
+                .count(4)
+                .generate_occurrences(start)
+                .unwrap()
+                .iter()
+                .map(|dt| dt.day())
+                .collect()
+        };
+
+        // Mon-Sun periods pair each Monday with the following Sunday...
+        assert_eq!(days(Weekday::Mon), vec![3, 9, 17, 23]);
+        // ...while Sun-Sat periods pair each Sunday with the following Monday
+        assert_eq!(days(Weekday::Sun), vec![3, 16, 17, 30]);
+
+        // Mon/Fri never straddle a Sun/Mon boundary, so both agree
+        let mon_fri = |wkst: Weekday| {
+            Recurrence::weekly()
+                .interval(2)
+                .weekdays(vec![Weekday::Mon, Weekday::Fri])
+                .week_start(wkst)
+                .count(6)
+                .generate_occurrences(start)
+                .unwrap()
+        };
+        assert_eq!(mon_fri(Weekday::Mon), mon_fri(Weekday::Sun));
+
+        let rrule = Recurrence::weekly().week_start(Weekday::Sun).to_rrule_string(start).unwrap();
+        assert!(rrule.ends_with(";WKST=SU"));
+        let rrule = Recurrence::weekly().to_rrule_string(start).unwrap();
+        assert!(!rrule.contains("WKST"));
+    }
+
+    #[test]
+    fn test_from_rrule_str_delegates_bysetpos_to_rrule() {
+        let tz = parse_timezone("America/New_York").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-01-06 09:00:00", tz).unwrap();
+
+        // First Monday of each month
+        let recurrence =
+            Recurrence::from_rrule_str(start, "FREQ=MONTHLY;BYDAY=MO;BYSETPOS=1;COUNT=6").unwrap();
+        let ours: Vec<_> = recurrence.occurrences(start).collect();
+
+        let expected: Vec<_> = "DTSTART;TZID=America/New_York:20250106T090000\nRRULE:FREQ=MONTHLY;BYDAY=MO;BYSETPOS=1;COUNT=6"
+            .parse::<rrule::RRuleSet>()
+            .unwrap()
+            .all(100)
+            .dates
+            .into_iter()
+            .map(|dt| dt.with_timezone(&tz))
+            .collect();
+
+        assert_eq!(ours, expected);
+        let days: Vec<_> = ours.iter().map(|d| (d.month(), d.day())).collect();
+        assert_eq!(days, vec![(1, 6), (2, 3), (3, 3), (4, 7), (5, 5), (6, 2)]);
+        assert!(ours.iter().all(|d| d.hour() == 9));
+        let exported = recurrence.to_rrule_string(start).unwrap();
+        assert!(exported.contains("BYDAY=MO") && exported.contains("BYSETPOS=1"));
+    }
+
+    #[test]
+    fn test_from_rrule_str_simple_rule_uses_builtin_fields() {
+        let tz = parse_timezone("UTC").unwrap();
+        let start = crate::timezone::parse_datetime_with_tz("2025-01-06 09:00:00", tz).unwrap();
+
+        let parsed =
+            Recurrence::from_rrule_str(start, "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;COUNT=5")
+                .unwrap();
+        let built = Recurrence::weekly()
+            .interval(2)
+            .weekdays(vec![rrule::Weekday::Mon, rrule::Weekday::Wed])
+            .count(5);
+        assert_eq!(parsed, built);
+
+        assert!(Recurrence::from_rrule_str(start, "FREQ=SOMETIMES").is_err());
+        assert!(Recurrence::from_rrule_str(start, "FREQ=MONTHLY;BYSETPOS=0").is_err());
+    }
 }
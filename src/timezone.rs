@@ -21,6 +21,111 @@ pub fn parse_timezone(tz_str: &str) -> Result<Tz> {
         .map_err(|_| EventixError::InvalidTimezone(tz_str.to_string()))
 }
 
+/// Windows timezone IDs (as emitted by Outlook/Exchange) mapped to their
+/// primary IANA zone, following the CLDR `windowsZones` territory "001" rows.
+const WINDOWS_TIMEZONES: &[(&str, &str)] = &[
+    ("Dateline Standard Time", "Etc/GMT+12"),
+    ("UTC-11", "Etc/GMT+11"),
+    ("Hawaiian Standard Time", "Pacific/Honolulu"),
+    ("Alaskan Standard Time", "America/Anchorage"),
+    ("Pacific Standard Time (Mexico)", "America/Tijuana"),
+    ("Pacific Standard Time", "America/Los_Angeles"),
+    ("US Mountain Standard Time", "America/Phoenix"),
+    ("Mountain Standard Time (Mexico)", "America/Mazatlan"),
+    ("Mountain Standard Time", "America/Denver"),
+    ("Central America Standard Time", "America/Guatemala"),
+    ("Central Standard Time (Mexico)", "America/Mexico_City"),
+    ("Central Standard Time", "America/Chicago"),
+    ("Canada Central Standard Time", "America/Regina"),
+    ("SA Pacific Standard Time", "America/Bogota"),
+    ("Eastern Standard Time (Mexico)", "America/Cancun"),
+    ("Eastern Standard Time", "America/New_York"),
+    ("US Eastern Standard Time", "America/Indianapolis"),
+    ("Venezuela Standard Time", "America/Caracas"),
+    ("Atlantic Standard Time", "America/Halifax"),
+    ("SA Western Standard Time", "America/La_Paz"),
+    ("Pacific SA Standard Time", "America/Santiago"),
+    ("Newfoundland Standard Time", "America/St_Johns"),
+    ("E. South America Standard Time", "America/Sao_Paulo"),
+    ("Argentina Standard Time", "America/Buenos_Aires"),
+    ("SA Eastern Standard Time", "America/Cayenne"),
+    ("Greenland Standard Time", "America/Godthab"),
+    ("UTC-02", "Etc/GMT+2"),
+    ("Azores Standard Time", "Atlantic/Azores"),
+    ("Cape Verde Standard Time", "Atlantic/Cape_Verde"),
+    ("UTC", "Etc/UTC"),
+    ("GMT Standard Time", "Europe/London"),
+    ("Greenwich Standard Time", "Atlantic/Reykjavik"),
+    ("W. Europe Standard Time", "Europe/Berlin"),
+    ("Central Europe Standard Time", "Europe/Budapest"),
+    ("Romance Standard Time", "Europe/Paris"),
+    ("Central European Standard Time", "Europe/Warsaw"),
+    ("W. Central Africa Standard Time", "Africa/Lagos"),
+    ("GTB Standard Time", "Europe/Bucharest"),
+    ("Middle East Standard Time", "Asia/Beirut"),
+    ("Egypt Standard Time", "Africa/Cairo"),
+    ("South Africa Standard Time", "Africa/Johannesburg"),
+    ("FLE Standard Time", "Europe/Kiev"),
+    ("Israel Standard Time", "Asia/Jerusalem"),
+    ("E. Europe Standard Time", "Europe/Chisinau"),
+    ("Turkey Standard Time", "Europe/Istanbul"),
+    ("Arab Standard Time", "Asia/Riyadh"),
+    ("Arabic Standard Time", "Asia/Baghdad"),
+    ("Russian Standard Time", "Europe/Moscow"),
+    ("E. Africa Standard Time", "Africa/Nairobi"),
+    ("Iran Standard Time", "Asia/Tehran"),
+    ("Arabian Standard Time", "Asia/Dubai"),
+    ("Afghanistan Standard Time", "Asia/Kabul"),
+    ("Pakistan Standard Time", "Asia/Karachi"),
+    ("West Asia Standard Time", "Asia/Tashkent"),
+    ("India Standard Time", "Asia/Calcutta"),
+    ("Sri Lanka Standard Time", "Asia/Colombo"),
+    ("Nepal Standard Time", "Asia/Katmandu"),
+    ("Central Asia Standard Time", "Asia/Almaty"),
+    ("Bangladesh Standard Time", "Asia/Dhaka"),
+    ("Myanmar Standard Time", "Asia/Rangoon"),
+    ("SE Asia Standard Time", "Asia/Bangkok"),
+    ("China Standard Time", "Asia/Shanghai"),
+    ("Singapore Standard Time", "Asia/Singapore"),
+    ("W. Australia Standard Time", "Australia/Perth"),
+    ("Taipei Standard Time", "Asia/Taipei"),
+    ("Tokyo Standard Time", "Asia/Tokyo"),
+    ("Korea Standard Time", "Asia/Seoul"),
+    ("Cen. Australia Standard Time", "Australia/Adelaide"),
+    ("AUS Central Standard Time", "Australia/Darwin"),
+    ("E. Australia Standard Time", "Australia/Brisbane"),
+    ("AUS Eastern Standard Time", "Australia/Sydney"),
+    ("West Pacific Standard Time", "Pacific/Port_Moresby"),
+    ("Tasmania Standard Time", "Australia/Hobart"),
+    ("Central Pacific Standard Time", "Pacific/Guadalcanal"),
+    ("New Zealand Standard Time", "Pacific/Auckland"),
+    ("Fiji Standard Time", "Pacific/Fiji"),
+    ("Tonga Standard Time", "Pacific/Tongatapu"),
+];
+
+/// Map a Windows timezone ID (e.g. `"Eastern Standard Time"`) to its IANA name
+pub fn windows_to_iana(windows_id: &str) -> Option<&'static str> {
+    WINDOWS_TIMEZONES
+        .iter()
+        .find(|(windows, _)| *windows == windows_id)
+        .map(|(_, iana)| *iana)
+}
+
+/// Parse an iCalendar `TZID` value, accepting IANA names as well as the
+/// Windows timezone IDs produced by Outlook and Exchange.
+pub(crate) fn parse_tzid(tzid: &str) -> Result<Tz> {
+    if let Ok(tz) = tzid.parse::<Tz>() {
+        return Ok(tz);
+    }
+    if let Some(iana) = windows_to_iana(tzid) {
+        return parse_timezone(iana);
+    }
+    if tzid.ends_with(" Time") {
+        return Err(EventixError::InvalidTimezone(format!("unmapped Windows timezone '{}'", tzid)));
+    }
+    Err(EventixError::InvalidTimezone(tzid.to_string()))
+}
+
 /// Parse a date/time string with timezone
 ///
 /// Accepts formats like:
@@ -61,6 +166,9 @@ pub fn parse_datetime_with_tz(datetime_str: &str, tz: Tz) -> Result<DateTime<Tz>
 
 /// Resolve a local datetime in a timezone, preserving wall-clock semantics
 /// across DST gaps by applying the pre-gap UTC offset.
+///
+/// Ambiguous wall-clock times (the repeated hour at a fall-back transition)
+/// resolve to the earliest instant, i.e. the pre-transition offset.
 pub(crate) fn resolve_local(tz: Tz, naive: NaiveDateTime) -> Option<DateTime<Tz>> {
     if let Some(dt) = tz.from_local_datetime(&naive).earliest() {
         return Some(dt);
@@ -93,6 +201,42 @@ pub(crate) fn local_day_window(date: NaiveDate, tz: Tz) -> Result<(DateTime<Tz>,
     Ok((start_dt, end_dt))
 }
 
+/// Parse an RFC 3339 / ISO 8601 datetime with an explicit offset
+///
+/// A `Z` suffix or zero offset resolves to UTC. Other whole-hour offsets
+/// resolve to the matching fixed-offset `Etc/GMT` zone (note that these use
+/// inverted signs, so `-04:00` maps to `Etc/GMT+4`). Offsets with minutes,
+/// such as `+05:30`, have no fixed-offset zone and are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use eventix::timezone::parse_rfc3339;
+///
+/// let dt = parse_rfc3339("2025-11-01T10:00:00-04:00").unwrap();
+/// assert_eq!(dt.timezone().name(), "Etc/GMT+4");
+/// ```
+pub fn parse_rfc3339(datetime_str: &str) -> Result<DateTime<Tz>> {
+    let parsed = DateTime::parse_from_rfc3339(datetime_str)
+        .map_err(|e| EventixError::DateTimeParse(format!("{}: {}", datetime_str, e)))?;
+
+    let offset_secs = parsed.offset().local_minus_utc();
+    if offset_secs % 3600 != 0 {
+        return Err(EventixError::InvalidTimezone(format!(
+            "No fixed-offset timezone for {}",
+            parsed.offset()
+        )));
+    }
+
+    let tz = match offset_secs / 3600 {
+        0 => Tz::UTC,
+        // Etc/GMT zones use POSIX sign convention: UTC-4 is Etc/GMT+4
+        hours => parse_timezone(&format!("Etc/GMT{:+}", -hours))?,
+    };
+
+    Ok(parsed.with_timezone(&tz))
+}
+
 /// Convert a datetime from one timezone to another
 ///
 /// # Examples
@@ -135,6 +279,19 @@ mod tests {
     use super::*;
     use chrono::{Duration, Timelike};
 
+    #[test]
+    fn test_parse_tzid_windows_names() {
+        assert_eq!(parse_tzid("Eastern Standard Time").unwrap(), chrono_tz::America::New_York);
+        assert_eq!(parse_tzid("Europe/Paris").unwrap(), chrono_tz::Europe::Paris);
+        // Every mapped IANA name must be one chrono-tz knows about
+        for (_, iana) in WINDOWS_TIMEZONES {
+            assert!(parse_timezone(iana).is_ok(), "{} is not a valid IANA zone", iana);
+        }
+
+        let err = parse_tzid("Atlantis Standard Time").unwrap_err();
+        assert!(err.to_string().contains("unmapped Windows timezone 'Atlantis Standard Time'"));
+    }
+
     #[test]
     fn test_parse_timezone() {
         assert!(parse_timezone("America/New_York").is_ok());
@@ -38,12 +38,12 @@ impl OwnedEventOccurrence {
     fn from_occurrence(occurrence: EventOccurrence<'_>) -> Self {
         Self {
             event_index: occurrence.event_index,
-            title: occurrence.event.title.clone(),
-            description: occurrence.event.description.clone(),
-            location: occurrence.event.location.clone(),
-            status: occurrence.event.status,
+            title: occurrence.title().to_string(),
+            description: occurrence.description().map(str::to_string),
+            location: occurrence.location().map(str::to_string),
+            status: occurrence.event.status.clone(),
             occurrence_time: occurrence.occurrence_time,
-            duration: occurrence.event.duration(),
+            duration: occurrence.end_time() - occurrence.occurrence_time,
         }
     }
 
@@ -3,6 +3,7 @@
 
 use eventix::{
     gap_validation, timezone, Calendar, Duration, Event, EventBuilder, EventStatus, EventixError,
+    Transparency,
 };
 
 #[test]
@@ -62,6 +63,49 @@ fn test_rescheduling_resets_cancelled_status() {
     assert_eq!(event.status, EventStatus::Confirmed); // Should be reset to Confirmed
 }
 
+#[test]
+fn test_reschedule_by_shifts_both_ends() {
+    let mut event = Event::builder()
+        .title("Meeting")
+        .start("2025-11-01 10:00:00", "UTC")
+        .duration_hours(1)
+        .build()
+        .unwrap();
+    let tz = timezone::parse_timezone("UTC").unwrap();
+
+    event.reschedule_by(Duration::minutes(30)).unwrap();
+    assert_eq!(
+        event.start_time,
+        timezone::parse_datetime_with_tz("2025-11-01 10:30:00", tz).unwrap()
+    );
+    assert_eq!(
+        event.end_time,
+        timezone::parse_datetime_with_tz("2025-11-01 11:30:00", tz).unwrap()
+    );
+    assert_eq!(event.duration(), Duration::hours(1));
+
+    event.reschedule_by(Duration::hours(-2)).unwrap();
+    assert_eq!(
+        event.start_time,
+        timezone::parse_datetime_with_tz("2025-11-01 08:30:00", tz).unwrap()
+    );
+    assert_eq!(event.duration(), Duration::hours(1));
+}
+
+#[test]
+fn test_reschedule_by_resets_cancelled_status() {
+    let mut event = Event::builder()
+        .title("Meeting")
+        .start("2025-11-01 10:00:00", "UTC")
+        .duration_hours(1)
+        .build()
+        .unwrap();
+
+    event.cancel();
+    event.reschedule_by(Duration::minutes(15)).unwrap();
+    assert_eq!(event.status, EventStatus::Confirmed);
+}
+
 #[test]
 fn test_rescheduling_validation() {
     let mut event = Event::builder()
@@ -140,6 +184,51 @@ fn test_gap_validation_ignores_cancelled_events() {
     assert!(cancelled_slot_gap.is_some(), "Should find a gap where the cancelled event is");
 }
 
+#[test]
+fn test_transparent_events_do_not_block_availability() {
+    let mut cal = Calendar::new("Availability");
+
+    cal.add_event(
+        Event::builder()
+            .title("Out of office note")
+            .start("2025-11-01 00:00:00", "UTC")
+            .duration_hours(24)
+            .transparency(Transparency::Transparent)
+            .build()
+            .unwrap(),
+    );
+    cal.add_event(
+        Event::builder()
+            .title("Standup")
+            .start("2025-11-01 09:00:00", "UTC")
+            .duration_hours(1)
+            .build()
+            .unwrap(),
+    );
+
+    let tz = timezone::parse_timezone("UTC").unwrap();
+    let start = timezone::parse_datetime_with_tz("2025-11-01 08:00:00", tz).unwrap();
+    let end = timezone::parse_datetime_with_tz("2025-11-01 12:00:00", tz).unwrap();
+
+    // The note is still part of the calendar...
+    assert_eq!(cal.get_events().len(), 2);
+    assert!(cal.get_events()[0].is_active());
+    assert!(!cal.get_events()[0].blocks_time());
+
+    // ...but only the standup occupies time
+    let gaps = gap_validation::find_gaps(&cal, start, end, Duration::minutes(30)).unwrap();
+    assert_eq!(gaps.len(), 2);
+    assert!(gap_validation::find_overlaps(&cal, start, end).unwrap().is_empty());
+
+    let density = gap_validation::calculate_density(&cal, start, end).unwrap();
+    assert_eq!(density.busy_duration, Duration::hours(1));
+    assert_eq!(density.event_count, 1);
+
+    let slot_start = timezone::parse_datetime_with_tz("2025-11-01 10:00:00", tz).unwrap();
+    let slot_end = timezone::parse_datetime_with_tz("2025-11-01 11:00:00", tz).unwrap();
+    assert!(gap_validation::is_slot_available(&cal, slot_start, slot_end).unwrap());
+}
+
 #[test]
 fn test_event_status_serialization() {
     let mut cal = Calendar::new("Test Calendar");
@@ -202,3 +291,44 @@ fn test_event_builder_end_validation_and_default_builder() {
         .unwrap();
     assert_eq!(event.title, "Default Builder");
 }
+
+fn status_calendar() -> Calendar {
+    let mut cal = Calendar::new("Bookings");
+    for (title, hour, status) in [
+        ("Kickoff", 9, EventStatus::Confirmed),
+        ("Maybe lunch", 12, EventStatus::Tentative),
+        ("Dropped", 14, EventStatus::Cancelled),
+        ("Hold", 16, EventStatus::Tentative),
+    ] {
+        cal.add_event(
+            Event::builder()
+                .title(title)
+                .start(&format!("2025-11-01 {:02}:00:00", hour), "UTC")
+                .duration_hours(1)
+                .status(status)
+                .build()
+                .unwrap(),
+        );
+    }
+    cal
+}
+
+#[test]
+fn test_calendar_retain_drops_cancelled_events() {
+    let mut cal = status_calendar();
+
+    cal.retain(|e| e.is_active());
+
+    assert_eq!(cal.event_count(), 3);
+    assert!(cal.find_events_by_title("Dropped").is_empty());
+}
+
+#[test]
+fn test_calendar_filter_by_status_returns_only_tentatives() {
+    let cal = status_calendar();
+
+    let tentative = cal.filter_by_status(EventStatus::Tentative);
+    let titles: Vec<&str> = tentative.iter().map(|e| e.title.as_str()).collect();
+    assert_eq!(titles, vec!["Maybe lunch", "Hold"]);
+    assert!(cal.filter_by_status(EventStatus::Blocked).is_empty());
+}
@@ -77,7 +77,7 @@ fn test_views_match_events_on_date_for_active_events() {
                     occurrence.title().to_string(),
                     occurrence.occurrence_time,
                     occurrence.end_time(),
-                    occurrence.event.status,
+                    occurrence.event.status.clone(),
                 )
             })
             .collect();
@@ -89,7 +89,7 @@ fn test_views_match_events_on_date_for_active_events() {
                     occurrence.title().to_string(),
                     occurrence.occurrence_time,
                     occurrence.end_time(),
-                    occurrence.status,
+                    occurrence.status.clone(),
                 )
             })
             .collect();
@@ -265,3 +265,103 @@ fn test_calendar_from_json_rejects_invalid_exdates_and_status() {
         matches!(err, EventixError::Other(message) if message.contains("Invalid event status"))
     );
 }
+
+#[test]
+fn test_events_on_date_covers_full_local_day_across_dst() {
+    let mut calendar = Calendar::new("Edges");
+    calendar.add_events(vec![
+        sample_event("Late", "2025-11-04 23:59:30", "America/New_York"),
+        sample_event("Next midnight", "2025-11-05 00:00:00", "America/New_York"),
+        sample_event("After gap", "2026-03-08 03:30:00", "America/New_York"),
+        sample_event("Spring late", "2026-03-08 23:30:00", "America/New_York"),
+    ]);
+
+    let normal_day = calendar
+        .events_on_date(parse("2025-11-04 12:00:00", "America/New_York"))
+        .unwrap();
+    let titles: Vec<&str> = normal_day.iter().map(|o| o.title()).collect();
+    assert_eq!(titles, vec!["Late"]);
+
+    // 2026-03-08 is a 23-hour spring-forward day in New York
+    let spring_day = calendar
+        .events_on_date(parse("2026-03-08 00:00:00", "America/New_York"))
+        .unwrap();
+    let titles: Vec<&str> = spring_day.iter().map(|o| o.title()).collect();
+    assert_eq!(titles, vec!["After gap", "Spring late"]);
+}
+
+#[test]
+fn test_next_event_returns_soonest_upcoming_active_occurrence() {
+    let mut cal = Calendar::new("Today");
+    for (title, time, status) in [
+        ("Morning", "09:00:00", EventStatus::Confirmed),
+        ("Cancelled lunch", "12:00:00", EventStatus::Cancelled),
+        ("Afternoon", "14:00:00", EventStatus::Confirmed),
+    ] {
+        cal.add_event(
+            Event::builder()
+                .title(title)
+                .start(&format!("2025-11-03 {}", time), "UTC")
+                .duration_hours(1)
+                .status(status)
+                .build()
+                .unwrap(),
+        );
+    }
+    cal.add_event(
+        Event::builder()
+            .title("Daily review")
+            .start("2025-10-01 17:00:00", "UTC")
+            .duration_minutes(30)
+            .recurrence(Recurrence::daily())
+            .build()
+            .unwrap(),
+    );
+
+    let next = cal.next_event(parse("2025-11-03 11:59:00", "UTC")).unwrap().unwrap();
+    assert_eq!(next.title(), "Afternoon");
+    assert_eq!(next.occurrence_time, parse("2025-11-03 14:00:00", "UTC"));
+
+    // Occurrences starting exactly at `after` count; in-progress ones do not
+    let next = cal.next_event(parse("2025-11-03 09:30:00", "UTC")).unwrap().unwrap();
+    assert_eq!(next.title(), "Afternoon");
+    let next = cal.next_event(parse("2025-11-03 17:00:00", "UTC")).unwrap().unwrap();
+    assert_eq!(next.title(), "Daily review");
+
+    // Only the unbounded series remains afterwards
+    let next = cal.next_event(parse("2026-01-01 00:00:00", "UTC")).unwrap().unwrap();
+    assert_eq!(next.occurrence_time, parse("2026-01-01 17:00:00", "UTC"));
+
+    assert!(Calendar::new("Empty")
+        .next_event(parse("2025-11-03 00:00:00", "UTC"))
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_next_event_skips_fully_filtered_unbounded_series() {
+    let mut cal = Calendar::new("Filtered");
+    // A Saturday series that skips weekends never occurs
+    cal.add_event(
+        Event::builder()
+            .title("Never")
+            .start("2025-11-01 09:00:00", "UTC")
+            .duration_hours(1)
+            .recurrence(Recurrence::weekly())
+            .skip_weekends(true)
+            .build()
+            .unwrap(),
+    );
+    cal.add_event(
+        Event::builder()
+            .title("Planning")
+            .start("2025-11-05 10:00:00", "UTC")
+            .duration_hours(1)
+            .build()
+            .unwrap(),
+    );
+
+    let next = cal.next_event(parse("2025-11-03 00:00:00", "UTC")).unwrap().unwrap();
+    assert_eq!(next.title(), "Planning");
+    assert!(cal.next_event(parse("2025-11-06 00:00:00", "UTC")).unwrap().is_none());
+}
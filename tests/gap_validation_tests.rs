@@ -881,3 +881,155 @@ fn test_gap_validation_cancelled_slots_and_alternative_suggestions() {
         ]
     );
 }
+
+#[test]
+fn test_availability_windows_keep_local_hours_across_fall_back() {
+    use chrono::{NaiveDate, NaiveTime, Timelike, Utc};
+
+    let tz = timezone::parse_timezone("America/New_York").unwrap();
+    let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+    let five = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+
+    // Fri Oct 31 (EDT) through Mon Nov 3 (EST); DST ends Sun Nov 2
+    let windows = gap_validation::availability_windows(
+        NaiveDate::from_ymd_opt(2025, 10, 31).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 11, 3).unwrap(),
+        tz,
+        nine,
+        five,
+        true,
+    )
+    .unwrap();
+
+    assert_eq!(windows.len(), 2);
+    for (open, close) in &windows {
+        assert_eq!(open.hour(), 9);
+        assert_eq!(close.hour(), 17);
+    }
+
+    // Same wall-clock hours map to different UTC instants on each side
+    assert_eq!(windows[0].0.with_timezone(&Utc).hour(), 13);
+    assert_eq!(windows[1].0.with_timezone(&Utc).hour(), 14);
+    assert_eq!(windows[1].1.with_timezone(&Utc).hour(), 22);
+
+    // A 9:00 local meeting after the transition sits exactly at the window start
+    let mut cal = Calendar::new("Bookings");
+    cal.add_event(
+        Event::builder()
+            .title("Client call")
+            .start("2025-11-03 09:00:00", "America/New_York")
+            .duration_hours(1)
+            .build()
+            .unwrap(),
+    );
+    let (open, close) = windows[1];
+    let gaps = gap_validation::find_gaps(&cal, open, close, Duration::minutes(30)).unwrap();
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].start, parse("2025-11-03 10:00:00", "America/New_York"));
+    assert_eq!(gaps[0].end, close);
+}
+
+#[test]
+fn test_suggest_alternatives_within_business_hours_ranked_by_proximity() {
+    use chrono::{NaiveTime, Timelike};
+
+    let mut cal = Calendar::new("Work");
+    for (title, time, hours) in [("Standup", "09:00:00", 1), ("Client", "13:00:00", 2)] {
+        cal.add_event(
+            Event::builder()
+                .title(title)
+                .start(&format!("2025-11-03 {}", time), "America/New_York")
+                .duration_hours(hours)
+                .build()
+                .unwrap(),
+        );
+    }
+
+    let requested = parse("2025-11-03 13:30:00", "America/New_York");
+    let business_hours =
+        (NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+
+    let suggestions = gap_validation::suggest_alternatives_within(
+        &cal,
+        requested,
+        Duration::hours(1),
+        Duration::hours(24),
+        business_hours,
+    )
+    .unwrap();
+
+    assert!(!suggestions.is_empty());
+    for slot in &suggestions {
+        let end = *slot + Duration::hours(1);
+        assert!(slot.hour() >= 9, "{} starts before business hours", slot);
+        assert!(end.hour() < 17 || (end.hour() == 17 && end.minute() == 0), "{} ends late", slot);
+        assert!(gap_validation::is_slot_available(&cal, *slot, end).unwrap());
+    }
+
+    // 12:00-13:00 ends right as the client meeting starts: 90 minutes away
+    assert_eq!(suggestions[0], parse("2025-11-03 12:00:00", "America/New_York"));
+    assert_eq!(suggestions[1], parse("2025-11-03 15:00:00", "America/New_York"));
+
+    // Ranked by distance from the request
+    let distances: Vec<_> = suggestions.iter().map(|s| (*s - requested).abs()).collect();
+    assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn test_density_large_calendar_same_in_serial_and_parallel_builds() {
+    // Runs with and without the `rayon` feature; both must produce these values.
+    // 25 days x 8 back-to-back 90-minute events starting hourly from 09:00.
+    let mut cal = Calendar::new("Shared");
+    for day in 1..=25 {
+        for slot in 0..8 {
+            cal.add_event(
+                Event::builder()
+                    .title(format!("Day {} slot {}", day, slot))
+                    .start(&format!("2025-11-{:02} {:02}:00:00", day, 9 + slot), "UTC")
+                    .duration_minutes(90)
+                    .build()
+                    .unwrap(),
+            );
+        }
+    }
+    assert_eq!(cal.event_count(), 200);
+
+    let start = parse("2025-11-01 00:00:00", "UTC");
+    let end = parse("2025-11-26 00:00:00", "UTC");
+    let density = gap_validation::calculate_density(&cal, start, end).unwrap();
+
+    assert_eq!(density.event_count, 200);
+    // Each day is busy 09:00-17:30
+    assert_eq!(density.busy_duration, Duration::minutes(25 * 510));
+    assert_eq!(density.free_duration, Duration::hours(600) - Duration::minutes(25 * 510));
+    // Consecutive events overlap by 30 minutes: 7 pairs per day
+    assert_eq!(density.overlap_count, 25 * 7);
+    // Before the first day, between days, and after the last day
+    assert_eq!(density.gap_count, 26);
+}
+
+#[test]
+fn test_overlaps_sweep_line_stress_2000_occurrences() {
+    // A staggered chain: each 45-minute event starts 30 minutes after the
+    // previous one, so it overlaps its neighbours and nothing else.
+    let mut cal = Calendar::new("Chain");
+    let first = parse("2025-11-01 00:00:00", "UTC");
+    for i in 0..2000 {
+        let start = first + Duration::minutes(30 * i);
+        cal.add_event(
+            Event::builder()
+                .title(format!("Link {}", i))
+                .start_datetime(start)
+                .end_datetime(start + Duration::minutes(45))
+                .build()
+                .unwrap(),
+        );
+    }
+
+    // Timing lives in benches/performance.rs (`find_overlaps/staggered_chain_2000`)
+    let end = first + Duration::days(60);
+    let overlaps = gap_validation::find_overlaps(&cal, first, end).unwrap();
+
+    assert_eq!(overlaps.len(), 1999);
+    assert!(overlaps.iter().all(|o| o.duration == Duration::minutes(15)));
+}
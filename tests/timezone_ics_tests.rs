@@ -400,3 +400,54 @@ END:VCALENDAR";
     assert_eq!(floating.description.as_deref(), Some("Floating exception date"));
     assert_eq!(floating.exdates, vec![parse("2025-11-06 09:00:00", "America/New_York")]);
 }
+
+#[test]
+fn test_split_by_timezone_groups_events_per_region() {
+    let mut cal = Calendar::new("Global");
+    cal.add_event(sample_event("NY standup", "2025-11-03 09:00:00", "America/New_York"));
+    cal.add_event(sample_event("NY review", "2025-11-03 15:00:00", "America/New_York"));
+    cal.add_event(sample_event("Tokyo sync", "2025-11-03 10:00:00", "Asia/Tokyo"));
+    cal.add_event(sample_event("UTC deploy", "2025-11-03 12:00:00", "UTC"));
+
+    let regions = cal.split_by_timezone();
+    assert_eq!(regions.len(), 3);
+
+    let new_york = timezone::parse_timezone("America/New_York").unwrap();
+    let tokyo = timezone::parse_timezone("Asia/Tokyo").unwrap();
+    let utc = timezone::parse_timezone("UTC").unwrap();
+    assert_eq!(regions[&new_york].event_count(), 2);
+    assert_eq!(regions[&tokyo].event_count(), 1);
+    assert_eq!(regions[&utc].event_count(), 1);
+    assert_eq!(regions[&tokyo].name, "Global (Asia/Tokyo)");
+
+    for (tz, sub) in &regions {
+        assert_eq!(sub.timezone, Some(*tz));
+        assert!(sub.get_events().iter().all(|e| e.timezone == *tz));
+    }
+
+    // The parent calendar is untouched
+    assert_eq!(cal.event_count(), 4);
+}
+
+#[test]
+fn test_ics_import_dtstart_and_dtend_in_different_timezones() {
+    // 09:00 New York (14:00 UTC) to 08:00 Los Angeles (16:00 UTC): the naive
+    // local difference is negative, the real duration is two hours.
+    let ics = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:flight@example.com
+SUMMARY:Flight JFK-LAX
+DTSTART;TZID=America/New_York:20251103T090000
+DTEND;TZID=America/Los_Angeles:20251103T080000
+END:VEVENT
+END:VCALENDAR";
+
+    let cal = Calendar::from_ics_string(ics).unwrap();
+    let event = &cal.get_events()[0];
+
+    assert_eq!(event.start_time, parse("2025-11-03 09:00:00", "America/New_York"));
+    assert_eq!(event.end_time, parse("2025-11-03 08:00:00", "America/Los_Angeles"));
+    assert_eq!(event.end_time.timezone().name(), "America/Los_Angeles");
+    assert_eq!(event.duration(), chrono::Duration::hours(2));
+}